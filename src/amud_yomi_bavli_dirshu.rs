@@ -1,7 +1,9 @@
 use icu_calendar::options::DateDifferenceOptions;
 
 use crate::{
+    annotations::{annotate_tractate_bounds, Annotations},
     constants::BAVLI_TOTAL_AMUDIM,
+    cycle::Cycle,
     date::{from_gregorian_date, DateExt, HebrewDate},
     interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
@@ -77,7 +79,7 @@ const fn iter(tractate: Tractate, iteration: i32) -> AmudIter {
         AmudIter::empty()
     }
 }
-fn iter_amud(iteration: i32) -> impl Iterator<Item = Amud> {
+pub(crate) fn iter_amud(iteration: i32) -> impl Iterator<Item = Amud> {
     BAVLI_TRACTATES.iter().flat_map(move |i| iter(*i, iteration))
 }
 #[derive(Default)]
@@ -91,6 +93,9 @@ impl InternalLimudCalculator<Amud> for AmudYomiBavliDirshu {
     fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
         hebrew_date.add_days(BAVLI_TOTAL_AMUDIM - 1)
     }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(BAVLI_TOTAL_AMUDIM)
+    }
 
     fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<Amud> {
         let cycle_iteration = interval.cycle.iteration?;
@@ -104,6 +109,22 @@ impl InternalLimudCalculator<Amud> for AmudYomiBavliDirshu {
             .days;
         iter.nth(offset as usize)
     }
+    fn tractates(&self) -> &'static [Tractate] {
+        &BAVLI_TRACTATES
+    }
+    fn tractate_unit_count(tractate: Tractate, iteration: i32) -> i32 {
+        iter(tractate, iteration).count() as i32
+    }
+    fn units_completed(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.cycle.start_date.days_until(limud_date)? + 1)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index)
+    }
+
+    fn annotate(&self, unit: &Amud, interval: &Interval, annotations: &mut Annotations) {
+        annotate_tractate_bounds(unit.tractate, *unit, interval, start_daf, end_daf, annotations);
+    }
 }
 impl LimudCalculator<Amud> for AmudYomiBavliDirshu {}
 
@@ -227,4 +248,15 @@ mod tests {
         assert_eq!(limud.side, Side::Aleph);
         assert_eq!(limud.tractate, Tractate::Pesachim);
     }
+
+    #[test]
+    fn amud_yomi_bavli_dirshu_cycles() {
+        let first = AmudYomiBavliDirshu::default()
+            .cycles()
+            .next()
+            .expect("first cycle exists");
+        assert_eq!(first.number, 1);
+        assert_eq!(first.start_date, from_gregorian_date(2023, 10, 16));
+        assert_eq!(first.unit_count, BAVLI_TOTAL_AMUDIM);
+    }
 }
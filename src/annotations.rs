@@ -0,0 +1,294 @@
+use hebrew_holiday_calendar::Holiday;
+
+use crate::{
+    daily_limudim::NoLimudReason,
+    date::{DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::InternalLimudCalculator,
+    location::Location,
+    units::Tractate,
+};
+
+/// Enough for every annotation a single date can produce, with room for several holidays.
+const MAX_ANNOTATIONS: usize = 16;
+
+/// Additional context about a date's limud.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Annotation {
+    /// A holiday or special day falls on this date
+    Holiday(Holiday),
+    /// The first unit of a tractate is learned on this date
+    TractateStart(Tractate),
+    /// The last unit of a tractate is learned on this date (a siyum)
+    Siyum(Tractate),
+    /// A new cycle of the schedule begins on this date
+    CycleStart {
+        /// The cycle number, for schedules with numbered cycles
+        cycle: Option<i32>,
+    },
+    /// The current cycle of the schedule ends on this date
+    CycleEnd {
+        /// The cycle number, for schedules with numbered cycles
+        cycle: Option<i32>,
+    },
+    /// Two perakim of Pirkei Avos are combined this week
+    CombinedPirkeiAvos(u8, u8),
+    /// There is no limud on this date
+    NoLimud(NoLimudReason),
+}
+
+/// The annotations of a date's limud.
+///
+/// Up to 16 annotations are kept, which is more than a single date produces. Should that ever be
+/// exceeded, the later annotations are dropped and [`Annotations::is_truncated`] returns `true`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotations {
+    items: [Option<Annotation>; MAX_ANNOTATIONS],
+    len: usize,
+    truncated: bool,
+}
+
+impl Default for Annotations {
+    fn default() -> Self {
+        Self {
+            items: core::array::from_fn(|_| None),
+            len: 0,
+            truncated: false,
+        }
+    }
+}
+
+impl Annotations {
+    pub(crate) fn push(&mut self, annotation: Annotation) {
+        if self.contains(&annotation) {
+            return;
+        }
+        if self.len < MAX_ANNOTATIONS {
+            self.items[self.len] = Some(annotation);
+            self.len += 1;
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    /// Iterate over the annotations.
+    pub fn iter(&self) -> impl Iterator<Item = &Annotation> {
+        self.items[..self.len].iter().flatten()
+    }
+
+    /// Whether the given annotation is present.
+    pub fn contains(&self, annotation: &Annotation) -> bool {
+        self.iter().any(|a| a == annotation)
+    }
+
+    /// The number of annotations.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no annotations.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether annotations were dropped because there were more than can be kept.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// Annotate the start of a tractate and its siyum, for schedules whose tractate boundaries are
+/// given by `start_daf` and `end_daf` functions.
+pub(crate) fn annotate_tractate_bounds<U: PartialEq>(
+    tractate: Tractate,
+    unit: U,
+    interval: &Interval,
+    start_daf: fn(Tractate, i32) -> U,
+    end_daf: fn(Tractate, i32) -> Option<U>,
+    annotations: &mut Annotations,
+) {
+    let Some(cycle_iteration) = interval.cycle.iteration else {
+        return;
+    };
+    if unit == start_daf(tractate, cycle_iteration) {
+        annotations.push(Annotation::TractateStart(tractate));
+    }
+    if Some(unit) == end_daf(tractate, cycle_iteration) {
+        annotations.push(Annotation::Siyum(tractate));
+    }
+}
+
+/// A limud along with its annotations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotatedLimud<T> {
+    /// The learning unit for this date, or `None` if no learning is scheduled
+    pub limud: Option<T>,
+    /// Holidays and schedule events on this date
+    pub annotations: Annotations,
+}
+
+pub(crate) fn annotated_limud<T>(
+    calculator: &impl InternalLimudCalculator<T>,
+    limud_date: HebrewDate,
+    location: Location,
+) -> AnnotatedLimud<T> {
+    let mut annotations = Annotations::default();
+    let mut skip_reason = None;
+    for holiday in limud_date.holidays_in(location) {
+        annotations.push(Annotation::Holiday(*holiday));
+        skip_reason = skip_reason.or(NoLimudReason::for_holiday(holiday));
+    }
+    let Some(interval) = calculator.find_interval(limud_date) else {
+        let reason = if calculator.find_cycle(limud_date).is_none() {
+            NoLimudReason::BeforeFirstCycle
+        } else {
+            NoLimudReason::NotScheduled
+        };
+        annotations.push(Annotation::NoLimud(reason));
+        return AnnotatedLimud {
+            limud: None,
+            annotations,
+        };
+    };
+    let cycle = interval.cycle;
+    if interval.start_date == cycle.start_date {
+        annotations.push(Annotation::CycleStart { cycle: cycle.iteration });
+    }
+    if interval.end_date >= cycle.end_date {
+        annotations.push(Annotation::CycleEnd { cycle: cycle.iteration });
+    }
+    if calculator.is_skip_interval(&interval) {
+        annotations.push(Annotation::NoLimud(calculator.skip_reason(skip_reason)));
+        return AnnotatedLimud {
+            limud: None,
+            annotations,
+        };
+    }
+    let limud = calculator.unit_for_interval(&interval, &limud_date);
+    match &limud {
+        Some(unit) => calculator.annotate(unit, &interval, &mut annotations),
+        None => annotations.push(Annotation::NoLimud(NoLimudReason::NotScheduled)),
+    }
+    AnnotatedLimud { limud, annotations }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+    use icu_calendar::types::Weekday;
+
+    use crate::{
+        date::{from_gregorian_date, from_hebrew_date},
+        DafYomiBavli, DafYomiYerushalmiVilna, MishnaYomis, PacePlanner, PirkeiAvos, PirkeiAvosUnit, Psalms, SkipRules,
+    };
+
+    use super::*;
+
+    #[test]
+    fn annotations_siyum_hashas() {
+        let test_date = from_gregorian_date(2020, 1, 4);
+        let annotated = annotated_limud(&DafYomiBavli::default(), test_date, Location::Diaspora);
+        assert!(annotated.limud.is_some());
+        assert!(annotated.annotations.contains(&Annotation::Siyum(Tractate::Niddah)));
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::CycleEnd { cycle: Some(13) }));
+        assert!(!annotated
+            .annotations
+            .contains(&Annotation::TractateStart(Tractate::Niddah)));
+    }
+
+    #[test]
+    fn annotations_cycle_start() {
+        let test_date = from_gregorian_date(2020, 1, 5);
+        let annotated = annotated_limud(&DafYomiBavli::default(), test_date, Location::Diaspora);
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::CycleStart { cycle: Some(14) }));
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::TractateStart(Tractate::Berachos)));
+    }
+
+    #[test]
+    fn annotations_mishna_spanning_two_tractates() {
+        let test_date = from_gregorian_date(2016, 4, 27);
+        let annotated = annotated_limud(&MishnaYomis, test_date, Location::Diaspora);
+        assert!(annotated.annotations.contains(&Annotation::Siyum(Tractate::Berachos)));
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::TractateStart(Tractate::Peah)));
+    }
+
+    #[test]
+    fn annotations_yerushalmi_yom_kippur() {
+        let test_date = from_hebrew_date(5778, HebrewMonth::Tishrei, 10);
+        let annotated = annotated_limud(&DafYomiYerushalmiVilna::default(), test_date, Location::Diaspora);
+        assert!(annotated.limud.is_none());
+        assert!(annotated.annotations.contains(&Annotation::Holiday(Holiday::YomKippur)));
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::NoLimud(NoLimudReason::YomKippur)));
+    }
+
+    #[test]
+    fn annotations_skip_reason_from_calculator() {
+        // Tisha B'Av 5784 was on a Tuesday, which this plan skips as a weekday
+        let start = from_hebrew_date(5784, HebrewMonth::Av, 1);
+        let deadline = from_hebrew_date(5784, HebrewMonth::Elul, 29);
+        let planner = PacePlanner::new(Psalms, 1, 150, start, deadline)
+            .expect("valid plan")
+            .skip_rules(SkipRules::new().skip_weekday(Weekday::Tuesday));
+        let test_date = from_hebrew_date(5784, HebrewMonth::Av, 9);
+        let annotated = annotated_limud(&planner, test_date, Location::Diaspora);
+        assert!(annotated.limud.is_none());
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::NoLimud(NoLimudReason::NotScheduled)));
+
+        let planner = planner.skip_rules(SkipRules::new().skip_yom_kippur_and_tisha_bav());
+        let annotated = annotated_limud(&planner, test_date, Location::Diaspora);
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::NoLimud(NoLimudReason::TishahBav)));
+    }
+
+    #[test]
+    fn annotations_before_first_cycle() {
+        let test_date = from_gregorian_date(1920, 1, 1);
+        let annotated = annotated_limud(&DafYomiBavli::default(), test_date, Location::Diaspora);
+        assert!(annotated
+            .annotations
+            .contains(&Annotation::NoLimud(NoLimudReason::BeforeFirstCycle)));
+    }
+
+    #[test]
+    fn annotations_combined_pirkei_avos() {
+        let test_date = from_hebrew_date(5778, HebrewMonth::Elul, 14);
+        let annotated = annotated_limud(
+            &PirkeiAvos::with_location(Location::Diaspora),
+            test_date,
+            Location::Diaspora,
+        );
+        assert_eq!(annotated.limud, Some(PirkeiAvosUnit::Combined(1, 2)));
+        assert!(annotated.annotations.contains(&Annotation::CombinedPirkeiAvos(1, 2)));
+    }
+
+    #[test]
+    fn annotations_truncated() {
+        let mut annotations = Annotations::default();
+        for cycle in 0..MAX_ANNOTATIONS as i32 {
+            annotations.push(Annotation::CycleStart { cycle: Some(cycle) });
+        }
+        // A duplicate is not an additional annotation
+        annotations.push(Annotation::CycleStart { cycle: Some(0) });
+        assert_eq!(annotations.len(), MAX_ANNOTATIONS);
+        assert!(!annotations.is_truncated());
+        annotations.push(Annotation::CycleEnd { cycle: None });
+        assert_eq!(annotations.len(), MAX_ANNOTATIONS);
+        assert!(annotations.is_truncated());
+        assert!(!annotations.contains(&Annotation::CycleEnd { cycle: None }));
+    }
+}
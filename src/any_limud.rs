@@ -0,0 +1,142 @@
+use crate::{
+    chitas::{ChitasUnit, ChumashAliyah},
+    date::HebrewDate,
+    limud_calculator::InternalLimudCalculator,
+    mishna_yomis::Mishnas,
+    parsha_hashavua::ParshaReading,
+    pirkei_avos::PirkeiAvosUnit,
+    rambam_yomi::{RambamChapters, RambamUnit},
+    tanach::TanachChapter,
+    tanach_929::Tanach929Unit,
+    tehillim_monthly::TehillimUnit,
+    units::{Amud, Daf},
+    AmudYomiBavliDirshu, Chitas, ChumashYomi, DafHashavuaBavli, DafYomiBavli, DafYomiYerushalmiVilna, MishnaYomis,
+    NachYomi, ParshaHashavua, PirkeiAvos, RambamYomi, RambamYomiOneChapter, Tanach929, TehillimMonthly,
+};
+
+/// A learning unit of any of the schedules supported by this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AnyLimud {
+    /// A daf of Talmud Bavli or Yerushalmi
+    Daf(Daf),
+    /// An amud of Talmud Bavli
+    Amud(Amud),
+    /// A pair of mishnayos
+    Mishnas(Mishnas),
+    /// An aliyah of the weekly parsha
+    Chumash(ChumashAliyah),
+    /// A day of Chitas
+    Chitas(ChitasUnit),
+    /// A chapter of Tanach
+    Tanach(TanachChapter),
+    /// A day of the 929 schedule
+    Tanach929(Tanach929Unit),
+    /// The parsha of the week
+    Parsha(ParshaReading),
+    /// A Pirkei Avos reading
+    PirkeiAvos(PirkeiAvosUnit),
+    /// A Tehillim reading
+    Tehillim(TehillimUnit),
+    /// A single Rambam Yomi unit
+    Rambam(RambamUnit),
+    /// A day of three Rambam Yomi chapters
+    RambamChapters(RambamChapters),
+}
+
+macro_rules! impl_from_unit {
+    ($($unit:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$unit> for AnyLimud {
+                fn from(unit: $unit) -> Self {
+                    AnyLimud::$variant(unit)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unit! {
+    Daf => Daf,
+    Amud => Amud,
+    Mishnas => Mishnas,
+    ChumashAliyah => Chumash,
+    ChitasUnit => Chitas,
+    TanachChapter => Tanach,
+    Tanach929Unit => Tanach929,
+    ParshaReading => Parsha,
+    PirkeiAvosUnit => PirkeiAvos,
+    TehillimUnit => Tehillim,
+    RambamUnit => Rambam,
+    RambamChapters => RambamChapters,
+}
+
+/// A type-erased limud calculator.
+///
+/// Unlike [`LimudCalculator`](crate::LimudCalculator), this trait is dyn-compatible,
+/// so calculators for different unit types can be stored together (e.g. `&dyn DynLimudCalculator`).
+pub trait DynLimudCalculator {
+    /// Calculate the limud for the given date.
+    ///
+    /// # Returns
+    /// The learning unit for this date, or `None` if no learning is scheduled
+    fn limud(&self, date: HebrewDate) -> Option<AnyLimud>;
+}
+
+macro_rules! impl_dyn_limud_calculator {
+    ($($calculator:ty => $unit:ty),* $(,)?) => {
+        $(
+            impl DynLimudCalculator for $calculator {
+                fn limud(&self, date: HebrewDate) -> Option<AnyLimud> {
+                    InternalLimudCalculator::<$unit>::limud(self, date).map(AnyLimud::from)
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn_limud_calculator! {
+    DafYomiBavli => Daf,
+    DafYomiYerushalmiVilna => Daf,
+    DafHashavuaBavli => Daf,
+    AmudYomiBavliDirshu => Amud,
+    MishnaYomis => Mishnas,
+    ChumashYomi => ChumashAliyah,
+    Chitas => ChitasUnit,
+    NachYomi => TanachChapter,
+    Tanach929 => Tanach929Unit,
+    ParshaHashavua => ParshaReading,
+    PirkeiAvos => PirkeiAvosUnit,
+    TehillimMonthly => TehillimUnit,
+    RambamYomi => RambamChapters,
+    RambamYomiOneChapter => RambamUnit,
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{date::from_gregorian_date, units::Tractate};
+
+    use super::*;
+
+    #[test]
+    fn any_limud_matches_typed_calculator() {
+        let test_date = from_gregorian_date(2017, 12, 28);
+        let calculator: &dyn DynLimudCalculator = &DafYomiBavli {};
+        let limud = calculator.limud(test_date).expect("limud exists");
+        assert_eq!(
+            limud,
+            AnyLimud::Daf(Daf {
+                tractate: Tractate::Shevuos,
+                page: 30
+            })
+        );
+    }
+
+    #[test]
+    fn any_limud_none_before_cycle_began() {
+        let test_date = from_gregorian_date(1920, 1, 1);
+        let calculator: &dyn DynLimudCalculator = &DafYomiBavli {};
+        assert!(calculator.limud(test_date).is_none());
+    }
+}
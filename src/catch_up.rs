@@ -0,0 +1,442 @@
+use core::marker::PhantomData;
+
+use icu_calendar::types::Weekday;
+
+use crate::{
+    date::{DateExt, HebrewDate},
+    sequence::{UnitRange, UnitSequence, UnitSpan},
+    skip_rules::weekday_index,
+    LimudCalculator,
+};
+
+/// How many days back to look for the public schedule's last limud before the start date
+const MAX_DAYS_WITHOUT_LIMUD: i32 = 7;
+
+/// The reason a catch-up plan could not be made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CatchUpError {
+    /// The last finished unit is not part of the sequence
+    UnknownUnit,
+    /// The target date is before the start date
+    TargetBeforeStart,
+    /// The public schedule has no learning in the days before the start date
+    NotScheduled,
+    /// The last finished unit comes after the public schedule's last limud, so it is from an
+    /// earlier cycle (or ahead of the schedule)
+    DifferentCycle,
+    /// The missed units cannot be learned by the target date within the limits
+    NotPossible {
+        /// The number of units the learner is behind the public schedule
+        units_behind: usize,
+        /// The most extra units that can be learned by the target date
+        max_extra_units: usize,
+    },
+}
+
+/// Plans extra learning to catch up with the public schedule of a fixed-cycle program.
+///
+/// The learner keeps learning the public limud each day, and learns the missed units on
+/// top of it, spread as evenly as the limits allow.
+///
+/// Catching up works within a single cycle of the program. A learner who is still in the previous
+/// cycle gets [`CatchUpError::DifferentCycle`].
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{BavliDafim, CatchUpPlanner, Daf, DafYomiBavli, Tractate};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let start = Date::try_new_iso(2017, 12, 28).unwrap().to_calendar(Hebrew);
+/// let target = Date::try_new_iso(2017, 12, 31).unwrap().to_calendar(Hebrew);
+/// let last_finished = Daf { tractate: Tractate::Shevuos, page: 25 };
+/// let plan = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+///     .max_extra_per_day(2)
+///     .plan(last_finished, start, target)
+///     .unwrap();
+/// assert_eq!(plan.units_behind(), 4);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CatchUpPlanner<S, C, T> {
+    sequence: S,
+    calculator: C,
+    max_extra_per_day: Option<usize>,
+    extra_weekdays: Option<[bool; 7]>,
+    unit: PhantomData<fn() -> T>,
+}
+
+impl<S, C, T> CatchUpPlanner<S, C, T>
+where
+    S: UnitSequence,
+    C: LimudCalculator<T>,
+    T: UnitSpan<S::Unit>,
+{
+    /// Create a planner for the program of `calculator`, whose units are ordered by `sequence`.
+    pub fn new(sequence: S, calculator: C) -> Self {
+        Self {
+            sequence,
+            calculator,
+            max_extra_per_day: None,
+            extra_weekdays: None,
+            unit: PhantomData,
+        }
+    }
+
+    /// Learn at most this many extra units on a single day.
+    pub fn max_extra_per_day(mut self, max_extra_per_day: usize) -> Self {
+        self.max_extra_per_day = Some(max_extra_per_day);
+        self
+    }
+
+    /// Only learn extra units on the given day of the week.
+    ///
+    /// Can be called more than once to allow several days of the week.
+    pub fn extra_only_on(mut self, weekday: Weekday) -> Self {
+        let extra_weekdays = self.extra_weekdays.get_or_insert([false; 7]);
+        extra_weekdays[weekday_index(weekday)] = true;
+        self
+    }
+
+    /// Plan to catch up from `start_date` until `target_date` (inclusive).
+    ///
+    /// # Returns
+    /// The plan, or the reason catching up is not possible
+    pub fn plan(
+        self,
+        last_finished: S::Unit,
+        start_date: HebrewDate,
+        target_date: HebrewDate,
+    ) -> Result<CatchUpPlan<S, C, T>, CatchUpError> {
+        if target_date < start_date {
+            return Err(CatchUpError::TargetBeforeStart);
+        }
+        let finished_index = self
+            .sequence
+            .position(&last_finished)
+            .ok_or(CatchUpError::UnknownUnit)?;
+        let public_index = self.last_public_index(start_date)?;
+        if finished_index > public_index {
+            return Err(CatchUpError::DifferentCycle);
+        }
+        let units_behind = public_index - finished_index;
+
+        let mut extra_days: usize = 0;
+        let mut date = start_date;
+        while date <= target_date {
+            if self.is_extra_day(&date) {
+                extra_days += 1;
+            }
+            date = date.add_days(1).ok_or(CatchUpError::NotScheduled)?;
+        }
+        if units_behind > 0 {
+            let max_extra_units = match self.max_extra_per_day {
+                Some(max) => extra_days.saturating_mul(max),
+                None if extra_days > 0 => usize::MAX,
+                None => 0,
+            };
+            if units_behind > max_extra_units {
+                return Err(CatchUpError::NotPossible {
+                    units_behind,
+                    max_extra_units,
+                });
+            }
+        }
+        Ok(CatchUpPlan {
+            planner: self,
+            first_missed_index: finished_index + 1,
+            units_behind,
+            extra_days,
+            start_date,
+            target_date,
+        })
+    }
+
+    /// The index of the last unit the public schedule learned before the given date
+    fn last_public_index(&self, date: HebrewDate) -> Result<usize, CatchUpError> {
+        for days_back in 1..=MAX_DAYS_WITHOUT_LIMUD {
+            let previous = date.add_days(-days_back).ok_or(CatchUpError::NotScheduled)?;
+            if let Some(limud) = self.calculator.limud(previous) {
+                return self
+                    .sequence
+                    .position(&limud.last_unit())
+                    .ok_or(CatchUpError::UnknownUnit);
+            }
+        }
+        Err(CatchUpError::NotScheduled)
+    }
+
+    fn is_extra_day(&self, date: &HebrewDate) -> bool {
+        self.extra_weekdays
+            .is_none_or(|weekdays| weekdays[(date.day_of_week_number() - 1) as usize])
+    }
+}
+
+/// A plan to catch up with the public schedule, made by [`CatchUpPlanner::plan`].
+#[derive(Debug, Clone, Copy)]
+pub struct CatchUpPlan<S, C, T> {
+    planner: CatchUpPlanner<S, C, T>,
+    first_missed_index: usize,
+    units_behind: usize,
+    extra_days: usize,
+    start_date: HebrewDate,
+    target_date: HebrewDate,
+}
+
+/// A single day of a [`CatchUpPlan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatchUpDay<T, U> {
+    /// The date
+    pub date: HebrewDate,
+    /// The public limud of the program on this date
+    pub public: Option<T>,
+    /// The missed units to learn on this date, in addition to the public limud
+    pub extra: Option<UnitRange<U>>,
+}
+
+impl<S, C, T> CatchUpPlan<S, C, T>
+where
+    S: UnitSequence,
+    C: LimudCalculator<T>,
+    T: UnitSpan<S::Unit>,
+{
+    /// The number of units the learner is behind the public schedule.
+    pub fn units_behind(&self) -> usize {
+        self.units_behind
+    }
+
+    /// Iterate over every day of the plan, from the start date until the target date.
+    pub fn days(&self) -> impl Iterator<Item = CatchUpDay<T, S::Unit>> + '_ {
+        let mut date = Some(self.start_date);
+        let mut extra_day = 0;
+        core::iter::from_fn(move || {
+            let current = date.filter(|current| *current <= self.target_date)?;
+            date = current.add_days(1);
+            let mut extra = None;
+            if self.planner.is_extra_day(&current) {
+                extra = self.extra_for_day(extra_day);
+                extra_day += 1;
+            }
+            Some(CatchUpDay {
+                date: current,
+                public: self.planner.calculator.limud(current),
+                extra,
+            })
+        })
+    }
+
+    /// The extra units for the given extra day, front loading the remainder
+    fn extra_for_day(&self, day: usize) -> Option<UnitRange<S::Unit>> {
+        if self.units_behind == 0 {
+            return None;
+        }
+        let offset = |day: usize| {
+            let per_day = self.units_behind / self.extra_days;
+            let remainder = self.units_behind % self.extra_days;
+            day * per_day + day.min(remainder)
+        };
+        let start = offset(day);
+        let end = offset(day + 1);
+        if end <= start {
+            return None;
+        }
+        Some(UnitRange {
+            start: self.planner.sequence.get(self.first_missed_index + start)?,
+            end: self.planner.sequence.get(self.first_missed_index + end - 1)?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        date::from_gregorian_date,
+        sequence::{BavliAmudim, BavliDafim},
+        units::{Amud, Daf, Side, Tractate},
+        AmudYomiBavliDirshu, DafYomiBavli,
+    };
+
+    use super::*;
+
+    fn shevuos(page: u16) -> Daf {
+        Daf {
+            tractate: Tractate::Shevuos,
+            page,
+        }
+    }
+
+    #[test]
+    fn catch_up_even_split() {
+        // The public daf on 2017-12-27 was Shevuos 29
+        let plan = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+            .plan(
+                shevuos(25),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 31),
+            )
+            .expect("plan is possible");
+        assert_eq!(plan.units_behind(), 4);
+        let mut days = plan.days();
+        let first = days.next().expect("day exists");
+        assert_eq!(first.public, Some(shevuos(30)));
+        assert_eq!(
+            first.extra,
+            Some(UnitRange {
+                start: shevuos(26),
+                end: shevuos(26)
+            })
+        );
+        let last = days.last().expect("day exists");
+        assert_eq!(last.date, from_gregorian_date(2017, 12, 31));
+        assert_eq!(
+            last.extra,
+            Some(UnitRange {
+                start: shevuos(29),
+                end: shevuos(29)
+            })
+        );
+    }
+
+    #[test]
+    fn catch_up_only_on_shabbos() {
+        let plan = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+            .extra_only_on(Weekday::Saturday)
+            .plan(
+                shevuos(25),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 31),
+            )
+            .expect("plan is possible");
+        let extra_days: [Option<UnitRange<Daf>>; 4] = {
+            let mut days = plan.days().map(|day| day.extra);
+            core::array::from_fn(|_| days.next().flatten())
+        };
+        // 2017-12-30 is Shabbos
+        assert_eq!(
+            extra_days,
+            [
+                None,
+                None,
+                Some(UnitRange {
+                    start: shevuos(26),
+                    end: shevuos(29)
+                }),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn catch_up_on_every_weekday_then_shabbos() {
+        let planner = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default());
+        let planner = [
+            Weekday::Sunday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+        ]
+        .into_iter()
+        .fold(planner, |planner, weekday| planner.extra_only_on(weekday))
+        .extra_only_on(Weekday::Saturday);
+        let plan = planner
+            .plan(
+                shevuos(25),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 31),
+            )
+            .expect("plan is possible");
+        // Every day of the week is still allowed, so the extra dafim are spread over all four days
+        assert!(plan.days().all(|day| day.extra.is_some()));
+    }
+
+    #[test]
+    fn catch_up_not_possible() {
+        let result = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+            .extra_only_on(Weekday::Saturday)
+            .max_extra_per_day(2)
+            .plan(
+                shevuos(25),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 31),
+            );
+        assert_eq!(
+            result.err(),
+            Some(CatchUpError::NotPossible {
+                units_behind: 4,
+                max_extra_units: 2
+            })
+        );
+    }
+
+    #[test]
+    fn catch_up_amudim_with_cap() {
+        // Amud Yomi began on 2023-10-16 with Berachos 2a
+        let plan = CatchUpPlanner::new(BavliAmudim, AmudYomiBavliDirshu::default())
+            .max_extra_per_day(2)
+            .plan(
+                Amud {
+                    tractate: Tractate::Berachos,
+                    page: 2,
+                    side: Side::Aleph,
+                },
+                from_gregorian_date(2023, 10, 21),
+                from_gregorian_date(2023, 10, 22),
+            )
+            .expect("plan is possible");
+        assert_eq!(plan.units_behind(), 4);
+        assert!(plan.days().all(|day| day.extra.is_some()));
+    }
+
+    #[test]
+    fn catch_up_already_caught_up() {
+        let plan = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+            .plan(
+                shevuos(29),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 28),
+            )
+            .expect("plan is possible");
+        assert_eq!(plan.units_behind(), 0);
+        assert!(plan.days().all(|day| day.extra.is_none()));
+    }
+
+    #[test]
+    fn catch_up_across_cycles() {
+        // The 13th cycle ended with Niddah on 2020-01-04, and 2020-01-06 learned Berachos 3
+        let result = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default()).plan(
+            Daf {
+                tractate: Tractate::Niddah,
+                page: 60,
+            },
+            from_gregorian_date(2020, 1, 7),
+            from_gregorian_date(2020, 2, 7),
+        );
+        assert_eq!(result.err(), Some(CatchUpError::DifferentCycle));
+    }
+
+    #[test]
+    fn catch_up_unlimited_extra_does_not_overflow() {
+        let plan = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default())
+            .max_extra_per_day(usize::MAX)
+            .plan(
+                shevuos(25),
+                from_gregorian_date(2017, 12, 28),
+                from_gregorian_date(2017, 12, 31),
+            )
+            .expect("plan is possible");
+        assert_eq!(plan.units_behind(), 4);
+    }
+
+    #[test]
+    fn catch_up_invalid_dates() {
+        let result = CatchUpPlanner::new(BavliDafim, DafYomiBavli::default()).plan(
+            shevuos(25),
+            from_gregorian_date(2017, 12, 28),
+            from_gregorian_date(2017, 12, 27),
+        );
+        assert_eq!(result.err(), Some(CatchUpError::TargetBeforeStart));
+    }
+}
@@ -0,0 +1,457 @@
+use core::fmt;
+
+use crate::{
+    sequence::{UnitRange, UnitSequence},
+    units::Tractate,
+};
+
+/// The most claims a [`Chaluka`] can hold
+const MAX_CLAIMS: usize = 64;
+
+/// The reason a claim could not be added to a [`Chaluka`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChalukaError {
+    /// A unit of the claim is not part of the sequence
+    UnknownUnit,
+    /// The claim ends before it starts
+    InvalidRange,
+    /// The participant number is not below the number of participants
+    UnknownParticipant,
+    /// The claim overlaps an earlier claim
+    Overlap,
+    /// The division already holds the most claims it can
+    TooManyClaims,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Claim {
+    participant: usize,
+    first_index: usize,
+    last_index: usize,
+}
+
+/// Divides a whole sequence (e.g. all of Mishnayos for a shloshim) among participants,
+/// also known as a siyum chaluka.
+///
+/// Participants are numbered from 0. Claimed ranges go to the participant who claimed them,
+/// and the rest of the sequence is divided in order among the participants without a claim,
+/// balanced by unit count.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{Chaluka, Mishnayos, Tractate};
+///
+/// let chaluka = Chaluka::new(Mishnayos, 10)
+///     .whole_tractates()
+///     .claim_tractate(0, Tractate::Berachos)
+///     .unwrap();
+/// let first = chaluka.shares().next().unwrap();
+/// assert_eq!(first.participant, Some(0));
+/// assert_eq!(first.range.end.tractate, Tractate::Berachos);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Chaluka<S> {
+    sequence: S,
+    participants: usize,
+    whole_tractates: bool,
+    claims: [Option<Claim>; MAX_CLAIMS],
+}
+
+/// A contiguous range of a [`Chaluka`] assigned to a single participant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChalukaShare<U> {
+    /// The participant, or `None` when every participant has a claim and the range is left over
+    pub participant: Option<usize>,
+    /// The units of the share
+    pub range: UnitRange<U>,
+    /// The number of units in the share
+    pub unit_count: usize,
+}
+
+impl<U: fmt::Display> fmt::Display for ChalukaShare<U> {
+    /// Formats as `<participant>: <start> - <end> (<unit count>)`, numbering participants from 1.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.participant {
+            Some(participant) => write!(f, "{}", participant + 1)?,
+            None => f.write_str("-")?,
+        }
+        write!(f, ": {} - {} ({})", self.range.start, self.range.end, self.unit_count)
+    }
+}
+
+impl<S: UnitSequence> Chaluka<S> {
+    /// Divide `sequence` among the given number of participants.
+    pub fn new(sequence: S, participants: usize) -> Self {
+        Self {
+            sequence,
+            participants,
+            whole_tractates: false,
+            claims: [None; MAX_CLAIMS],
+        }
+    }
+
+    /// Only divide the unclaimed units between tractates, keeping each tractate whole.
+    ///
+    /// Has no effect on sequences that are not divided into tractates.
+    pub fn whole_tractates(mut self) -> Self {
+        self.whole_tractates = true;
+        self
+    }
+
+    /// Assign the units from `start` to `end` (inclusive) to a participant.
+    pub fn claim(mut self, participant: usize, start: S::Unit, end: S::Unit) -> Result<Self, ChalukaError> {
+        if participant >= self.participants {
+            return Err(ChalukaError::UnknownParticipant);
+        }
+        let first_index = self.sequence.position(&start).ok_or(ChalukaError::UnknownUnit)?;
+        let last_index = self.sequence.position(&end).ok_or(ChalukaError::UnknownUnit)?;
+        if last_index < first_index {
+            return Err(ChalukaError::InvalidRange);
+        }
+        if self
+            .claims()
+            .any(|claim| first_index <= claim.last_index && claim.first_index <= last_index)
+        {
+            return Err(ChalukaError::Overlap);
+        }
+        let slot = self
+            .claims
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(ChalukaError::TooManyClaims)?;
+        *slot = Some(Claim {
+            participant,
+            first_index,
+            last_index,
+        });
+        Ok(self)
+    }
+
+    /// Assign a whole tractate to a participant.
+    pub fn claim_tractate(self, participant: usize, tractate: Tractate) -> Result<Self, ChalukaError> {
+        let mut units = self
+            .sequence
+            .units()
+            .filter(|unit| self.sequence.tractate(unit) == Some(tractate));
+        let start = units.next().ok_or(ChalukaError::UnknownUnit)?;
+        let end = units.last().unwrap_or(start);
+        self.claim(participant, start, end)
+    }
+
+    /// Iterate over the shares of the division, in sequence order.
+    ///
+    /// A participant can have several shares, e.g. a claim and a share of the unclaimed units,
+    /// and participants left without units have no shares.
+    pub fn shares(&self) -> impl Iterator<Item = ChalukaShare<S::Unit>> + '_ {
+        let mut units = self.sequence.units().enumerate();
+        let mut cursor = Cursor {
+            pool_index: 0,
+            free: 0,
+            free_end: self.cut(1, 0),
+        };
+        let mut pending = None;
+        core::iter::from_fn(move || {
+            let (start, participant) = match pending.take() {
+                Some(pending) => pending,
+                None => {
+                    let (index, unit) = units.next()?;
+                    (unit, cursor.owner(self, index))
+                }
+            };
+            let mut end = start;
+            let mut unit_count = 1;
+            for (index, unit) in units.by_ref() {
+                let owner = cursor.owner(self, index);
+                if owner != participant {
+                    pending = Some((unit, owner));
+                    break;
+                }
+                end = unit;
+                unit_count += 1;
+            }
+            Some(ChalukaShare {
+                participant,
+                range: UnitRange { start, end },
+                unit_count,
+            })
+        })
+    }
+
+    /// A summary of the division with one share per line, in the format of [`ChalukaShare`]'s
+    /// `Display` implementation.
+    pub fn summary(&self) -> impl fmt::Display + '_
+    where
+        S::Unit: fmt::Display,
+    {
+        Summary(self)
+    }
+
+    fn claims(&self) -> impl Iterator<Item = &Claim> {
+        self.claims.iter().flatten()
+    }
+
+    fn claimed_by(&self, index: usize) -> Option<usize> {
+        self.claims()
+            .find(|claim| claim.first_index <= index && index <= claim.last_index)
+            .map(|claim| claim.participant)
+    }
+
+    /// The participants without a claim
+    fn free_participants(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.participants).filter(|participant| self.claims().all(|claim| claim.participant != *participant))
+    }
+
+    /// The number of unclaimed units
+    fn pool_len(&self) -> usize {
+        let claimed: usize = self
+            .claims()
+            .map(|claim| claim.last_index - claim.first_index + 1)
+            .sum();
+        self.sequence.len() - claimed
+    }
+
+    /// The unclaimed unit index at which the given free participant's share begins,
+    /// not before `min`
+    fn cut(&self, free: usize, min: usize) -> usize {
+        let free_count = self.free_participants().count();
+        let pool_len = self.pool_len();
+        if free >= free_count {
+            return pool_len;
+        }
+        let target = free * (pool_len / free_count) + free.min(pool_len % free_count);
+        if !self.whole_tractates {
+            return target.max(min);
+        }
+
+        // The closest tractate boundary among the unclaimed units
+        let mut best = pool_len;
+        let mut pool_index = 0;
+        let mut previous = None;
+        for (index, unit) in self.sequence.units().enumerate() {
+            if self.claimed_by(index).is_some() {
+                continue;
+            }
+            let tractate = self.sequence.tractate(&unit);
+            let is_boundary = tractate.is_none() || previous.is_none() || tractate != previous;
+            if is_boundary && pool_index >= min && pool_index.abs_diff(target) < best.abs_diff(target) {
+                best = pool_index;
+            }
+            if pool_index > target && pool_index >= min && is_boundary {
+                break;
+            }
+            previous = tractate;
+            pool_index += 1;
+        }
+        best
+    }
+}
+
+/// Tracks which free participant owns the unclaimed units while walking the sequence
+struct Cursor {
+    pool_index: usize,
+    free: usize,
+    free_end: usize,
+}
+
+impl Cursor {
+    fn owner<S: UnitSequence>(&mut self, chaluka: &Chaluka<S>, index: usize) -> Option<usize> {
+        if let Some(participant) = chaluka.claimed_by(index) {
+            return Some(participant);
+        }
+        let pool_index = self.pool_index;
+        self.pool_index += 1;
+        while pool_index >= self.free_end && self.free_end < chaluka.pool_len() {
+            self.free += 1;
+            self.free_end = chaluka.cut(self.free + 1, self.free_end);
+        }
+        chaluka.free_participants().nth(self.free)
+    }
+}
+
+struct Summary<'a, S>(&'a Chaluka<S>);
+
+impl<S: UnitSequence> fmt::Display for Summary<'_, S>
+where
+    S::Unit: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line, share) in self.0.shares().enumerate() {
+            if line > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{share}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use core::fmt::Write;
+
+    use crate::{
+        sequence::{BavliDafim, Mishnayos, Psalms},
+        units::Daf,
+    };
+
+    use super::*;
+
+    /// A fixed size buffer to format into without allocating
+    struct Buffer {
+        bytes: [u8; 256],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    impl Buffer {
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).expect("valid utf8")
+        }
+    }
+
+    #[test]
+    fn chaluka_even_split() {
+        let chaluka = Chaluka::new(Psalms, 4);
+        let shares: [Option<ChalukaShare<u8>>; 5] = {
+            let mut shares = chaluka.shares();
+            core::array::from_fn(|_| shares.next())
+        };
+        // 150 psalms: 38, 38, 37, 37
+        assert_eq!(
+            shares[0].map(|share| (share.range.start, share.range.end)),
+            Some((1, 38))
+        );
+        assert_eq!(
+            shares[1].map(|share| (share.range.start, share.range.end)),
+            Some((39, 76))
+        );
+        assert_eq!(
+            shares[3].map(|share| (share.participant, share.range.end)),
+            Some((Some(3), 150))
+        );
+        assert!(shares[4].is_none());
+    }
+
+    #[test]
+    fn chaluka_summary() {
+        let chaluka = Chaluka::new(Psalms, 3);
+        let mut buffer = Buffer {
+            bytes: [0; 256],
+            len: 0,
+        };
+        write!(buffer, "{}", chaluka.summary()).expect("summary fits");
+        assert_eq!(buffer.as_str(), "1: 1 - 50 (50)\n2: 51 - 100 (50)\n3: 101 - 150 (50)");
+    }
+
+    #[test]
+    fn chaluka_share_tractate_names() {
+        let chaluka = Chaluka::new(BavliDafim, 1)
+            .claim(
+                0,
+                Daf {
+                    tractate: Tractate::BavaKamma,
+                    page: 2,
+                },
+                Daf {
+                    tractate: Tractate::BavaMetzia,
+                    page: 119,
+                },
+            )
+            .expect("claim is valid");
+        let share = chaluka
+            .shares()
+            .find(|share| share.participant == Some(0))
+            .expect("share exists");
+        let mut buffer = Buffer {
+            bytes: [0; 256],
+            len: 0,
+        };
+        write!(buffer, "{share}").expect("share fits");
+        assert_eq!(buffer.as_str(), "1: Bava Kamma 2 - Bava Metzia 119 (236)");
+    }
+
+    #[test]
+    fn chaluka_whole_tractates() {
+        let chaluka = Chaluka::new(BavliDafim, 5).whole_tractates();
+        let mut total = 0;
+        let mut participants = 0;
+        for share in chaluka.shares() {
+            // Each share starts at the beginning of a tractate
+            assert_eq!(share.range.start.page, 2);
+            let next = BavliDafim
+                .get(BavliDafim.position(&share.range.end).expect("daf exists") + 1)
+                .map(|daf| daf.tractate);
+            assert_ne!(next, Some(share.range.end.tractate));
+            assert!(share.unit_count.abs_diff(2711 / 5) < 300);
+            total += share.unit_count;
+            participants += 1;
+        }
+        assert_eq!((total, participants), (2711, 5));
+    }
+
+    #[test]
+    fn chaluka_claims() {
+        let chaluka = Chaluka::new(Mishnayos, 3)
+            .claim_tractate(2, Tractate::Avos)
+            .expect("claim is valid")
+            .whole_tractates();
+        let avos = chaluka
+            .shares()
+            .find(|share| share.participant == Some(2))
+            .expect("claim exists");
+        assert_eq!(avos.range.start.tractate, Tractate::Avos);
+        assert_eq!(avos.range.end.tractate, Tractate::Avos);
+        assert_eq!(chaluka.shares().filter(|share| share.participant == Some(2)).count(), 1);
+        let unclaimed: usize = chaluka
+            .shares()
+            .filter(|share| share.participant != Some(2))
+            .map(|share| share.unit_count)
+            .sum();
+        assert_eq!(unclaimed + avos.unit_count, 4192);
+    }
+
+    #[test]
+    fn chaluka_invalid_claims() {
+        let daf = |page| Daf {
+            tractate: Tractate::Berachos,
+            page,
+        };
+        let chaluka = Chaluka::new(BavliDafim, 2);
+        assert_eq!(
+            chaluka.claim(2, daf(2), daf(10)).err(),
+            Some(ChalukaError::UnknownParticipant)
+        );
+        assert_eq!(
+            chaluka.claim(0, daf(10), daf(2)).err(),
+            Some(ChalukaError::InvalidRange)
+        );
+        assert_eq!(
+            chaluka.claim(0, daf(2), daf(100)).err(),
+            Some(ChalukaError::UnknownUnit)
+        );
+        let claimed = chaluka.claim(0, daf(2), daf(10)).expect("claim is valid");
+        assert_eq!(claimed.claim(1, daf(10), daf(20)).err(), Some(ChalukaError::Overlap));
+    }
+
+    #[test]
+    fn chaluka_every_participant_claims() {
+        let chaluka = Chaluka::new(Psalms, 1).claim(0, 1, 100).expect("claim is valid");
+        let mut shares = chaluka.shares();
+        assert_eq!(shares.next().map(|share| share.participant), Some(Some(0)));
+        let leftover = shares.next().expect("leftover exists");
+        assert_eq!((leftover.participant, leftover.unit_count), (None, 50));
+    }
+}
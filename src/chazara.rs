@@ -0,0 +1,334 @@
+use core::marker::PhantomData;
+
+use icu_calendar::types::Weekday;
+
+use crate::{
+    date::{DateExt, HebrewDate},
+    sequence::{UnitRange, UnitSequence, UnitSpan},
+    skip_rules::weekday_index,
+    LimudCalculator,
+};
+
+/// The most reviews a [`Chazara`] schedule can hold
+const MAX_REVIEWS: usize = 8;
+
+/// The most learning days a single review can come due for (a weekly review covers six days)
+const MAX_DAYS_PER_REVIEW: usize = 6;
+
+const MAX_REVIEW_RANGES: usize = MAX_REVIEWS * MAX_DAYS_PER_REVIEW;
+
+/// How long after learning a unit it is reviewed.
+///
+/// Months and years are Hebrew months and years. When the day does not exist in the
+/// review month (e.g. the 30th of a 29 day month), the review is on the last day of that month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReviewInterval {
+    /// A number of days
+    Days(u16),
+    /// A number of Hebrew months
+    Months(u8),
+    /// A number of Hebrew years
+    Years(u8),
+}
+
+/// A rule for when learned units come due for review.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Review {
+    /// Review the units learned this long ago
+    After(ReviewInterval),
+    /// On this day of the week, review the units learned on the six days before it
+    WeeklyOn(Weekday),
+}
+
+impl Review {
+    /// Call `f` with every learning date whose units come due for review on `date`
+    fn learning_dates(&self, date: &HebrewDate, mut f: impl FnMut(HebrewDate)) {
+        match *self {
+            Review::After(ReviewInterval::Days(days)) => {
+                if let Some(learned) = date.add_days(-(days as i32)) {
+                    f(learned);
+                }
+            }
+            Review::After(ReviewInterval::Months(months)) => {
+                clamped_learning_dates(date, |day, sign| day.add_months(sign * months as i32), f);
+            }
+            Review::After(ReviewInterval::Years(years)) => {
+                clamped_learning_dates(date, |day, sign| day.add_years(sign * years as i32), f);
+            }
+            Review::WeeklyOn(weekday) => {
+                if (date.day_of_week_number() - 1) as usize == weekday_index(weekday) {
+                    for days_back in 1..=MAX_DAYS_PER_REVIEW as i32 {
+                        if let Some(learned) = date.add_days(-days_back) {
+                            f(learned);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Call `f` with every learning date that `add` (called with a sign of 1) moves to `date`.
+///
+/// Several dates can move to the same date when the day is constrained to the end of a shorter month.
+fn clamped_learning_dates(
+    date: &HebrewDate,
+    add: impl Fn(&HebrewDate, i32) -> Option<HebrewDate>,
+    mut f: impl FnMut(HebrewDate),
+) {
+    let Some(mut learned) = add(date, -1) else {
+        return;
+    };
+    for _ in 0..MAX_DAYS_PER_REVIEW {
+        if add(&learned, 1) != Some(*date) {
+            break;
+        }
+        f(learned);
+        let Some(next) = learned.add_days(1) else {
+            break;
+        };
+        learned = next;
+    }
+}
+
+/// A spaced-repetition review (chazara) schedule on top of any program.
+///
+/// Each day has the program's new learning, along with the units learned earlier that
+/// come due for review, merged into ranges of the program's sequence.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{BavliDafim, Chazara, DafYomiBavli};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let chazara = Chazara::weekly_on_shabbos(BavliDafim, DafYomiBavli::default());
+/// // Friday has no review, Shabbos reviews the six dafim learned Sunday to Friday
+/// let friday = Date::try_new_iso(2017, 12, 29).unwrap().to_calendar(Hebrew);
+/// let shabbos = Date::try_new_iso(2017, 12, 30).unwrap().to_calendar(Hebrew);
+/// assert_eq!(chazara.day(friday).reviews().count(), 0);
+/// let day = chazara.day(shabbos);
+/// let review = day.reviews().next().unwrap();
+/// assert_eq!((review.start.page, review.end.page), (26, 31));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Chazara<S, C, T> {
+    sequence: S,
+    calculator: C,
+    reviews: [Option<Review>; MAX_REVIEWS],
+    unit: PhantomData<fn() -> T>,
+}
+
+/// A single day of a [`Chazara`] schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChazaraDay<T, U> {
+    /// The date
+    pub date: HebrewDate,
+    /// The program's new learning on this date
+    pub new: Option<T>,
+    reviews: [Option<UnitRange<U>>; MAX_REVIEW_RANGES],
+}
+
+impl<T, U> ChazaraDay<T, U> {
+    /// Iterate over the ranges of units to review on this date, in sequence order.
+    pub fn reviews(&self) -> impl Iterator<Item = &UnitRange<U>> {
+        self.reviews.iter().flatten()
+    }
+}
+
+impl<S, C, T> Chazara<S, C, T>
+where
+    S: UnitSequence,
+    C: LimudCalculator<T>,
+    T: UnitSpan<S::Unit>,
+{
+    /// Create a schedule without any reviews, for the program of `calculator`
+    /// whose units are ordered by `sequence`.
+    pub fn new(sequence: S, calculator: C) -> Self {
+        Self {
+            sequence,
+            calculator,
+            reviews: [None; MAX_REVIEWS],
+            unit: PhantomData,
+        }
+    }
+
+    /// The traditional custom of reviewing the week's learning on Shabbos.
+    pub fn weekly_on_shabbos(sequence: S, calculator: C) -> Self {
+        Self::new(sequence, calculator).review(Review::WeeklyOn(Weekday::Saturday))
+    }
+
+    /// Review each unit 1 day, 1 week, 1 month and 1 year after it is learned.
+    pub fn spaced_repetition(sequence: S, calculator: C) -> Self {
+        Self::new(sequence, calculator)
+            .review(Review::After(ReviewInterval::Days(1)))
+            .review(Review::After(ReviewInterval::Days(7)))
+            .review(Review::After(ReviewInterval::Months(1)))
+            .review(Review::After(ReviewInterval::Years(1)))
+    }
+
+    /// Add a review rule.
+    ///
+    /// A schedule holds at most 8 rules, further rules are ignored.
+    pub fn review(mut self, review: Review) -> Self {
+        if let Some(slot) = self.reviews.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(review);
+        }
+        self
+    }
+
+    /// Calculate the new learning and the reviews for a date.
+    pub fn day(&self, date: HebrewDate) -> ChazaraDay<T, S::Unit> {
+        let mut ranges = [(0, 0); MAX_REVIEW_RANGES];
+        let mut len = 0;
+        for review in self.reviews.iter().flatten() {
+            review.learning_dates(&date, |learned| {
+                if let Some(range) = self.index_range(learned) {
+                    if len < MAX_REVIEW_RANGES {
+                        ranges[len] = range;
+                        len += 1;
+                    }
+                }
+            });
+        }
+
+        // Merge overlapping and adjacent ranges
+        let ranges = &mut ranges[..len];
+        ranges.sort_unstable();
+        let mut reviews = [None; MAX_REVIEW_RANGES];
+        let mut merged: Option<(usize, usize)> = None;
+        let mut count = 0;
+        for &(start, end) in ranges.iter() {
+            merged = match merged {
+                Some((merged_start, merged_end)) if start <= merged_end + 1 => {
+                    Some((merged_start, merged_end.max(end)))
+                }
+                Some(previous) => {
+                    reviews[count] = self.unit_range(previous);
+                    count += 1;
+                    Some((start, end))
+                }
+                None => Some((start, end)),
+            };
+        }
+        if let Some(last) = merged {
+            reviews[count] = self.unit_range(last);
+        }
+
+        ChazaraDay {
+            date,
+            new: self.calculator.limud(date),
+            reviews,
+        }
+    }
+
+    /// The sequence indices of the units learned on a date
+    fn index_range(&self, date: HebrewDate) -> Option<(usize, usize)> {
+        let limud = self.calculator.limud(date)?;
+        let start = self.sequence.position(&limud.first_unit())?;
+        let end = self.sequence.position(&limud.last_unit())?;
+        Some((start, end))
+    }
+
+    fn unit_range(&self, (start, end): (usize, usize)) -> Option<UnitRange<S::Unit>> {
+        Some(UnitRange {
+            start: self.sequence.get(start)?,
+            end: self.sequence.get(end)?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+
+    use crate::{
+        date::{from_gregorian_date, from_hebrew_date},
+        limud_calculator::InternalLimudCalculator,
+        sequence::{BavliDafim, Mishnayos},
+        units::{Daf, Tractate},
+        DafYomiBavli, MishnaYomis,
+    };
+
+    use super::*;
+
+    fn shevuos(start: u16, end: u16) -> UnitRange<Daf> {
+        UnitRange {
+            start: Daf {
+                tractate: Tractate::Shevuos,
+                page: start,
+            },
+            end: Daf {
+                tractate: Tractate::Shevuos,
+                page: end,
+            },
+        }
+    }
+
+    #[test]
+    fn chazara_weekly_on_shabbos() {
+        let chazara = Chazara::weekly_on_shabbos(BavliDafim, DafYomiBavli::default());
+        let shabbos = chazara.day(from_gregorian_date(2017, 12, 30));
+        assert_eq!(
+            shabbos.new,
+            Some(Daf {
+                tractate: Tractate::Shevuos,
+                page: 32
+            })
+        );
+        let mut reviews = shabbos.reviews();
+        assert_eq!(reviews.next(), Some(&shevuos(26, 31)));
+        assert_eq!(reviews.next(), None);
+        let sunday = chazara.day(from_gregorian_date(2017, 12, 31));
+        assert_eq!(sunday.reviews().count(), 0);
+    }
+
+    #[test]
+    fn chazara_spaced_repetition() {
+        let chazara = Chazara::spaced_repetition(BavliDafim, DafYomiBavli::default());
+        let day = chazara.day(from_gregorian_date(2017, 12, 28));
+        // A year, a month, a week and a day ago, in sequence order
+        let reviews: [Option<&UnitRange<Daf>>; 5] = {
+            let mut reviews = day.reviews();
+            core::array::from_fn(|_| reviews.next())
+        };
+        let single = |tractate, page| UnitRange {
+            start: Daf { tractate, page },
+            end: Daf { tractate, page },
+        };
+        assert_eq!(reviews[0], Some(&single(Tractate::BavaMetzia, 104)));
+        assert_eq!(reviews[1], Some(&single(Tractate::Makkos, 23)));
+        assert_eq!(reviews[2], Some(&shevuos(23, 23)));
+        assert_eq!(reviews[3], Some(&shevuos(29, 29)));
+        assert_eq!(reviews[4], None);
+    }
+
+    #[test]
+    fn chazara_merges_adjacent_ranges() {
+        let chazara = Chazara::new(BavliDafim, DafYomiBavli::default())
+            .review(Review::After(ReviewInterval::Days(1)))
+            .review(Review::After(ReviewInterval::Days(2)));
+        let day = chazara.day(from_gregorian_date(2017, 12, 28));
+        let mut reviews = day.reviews();
+        assert_eq!(reviews.next(), Some(&shevuos(28, 29)));
+        assert_eq!(reviews.next(), None);
+    }
+
+    #[test]
+    fn chazara_month_review_at_end_of_short_month() {
+        // Cheshvan 5778 has 29 days, so Tishrei 29 and 30 are both reviewed on Cheshvan 29
+        let chazara = Chazara::new(Mishnayos, MishnaYomis).review(Review::After(ReviewInterval::Months(1)));
+        let day = chazara.day(from_hebrew_date(5778, HebrewMonth::Cheshvan, 29));
+        let review = day.reviews().next().expect("review exists");
+        let first = MishnaYomis
+            .limud(from_hebrew_date(5778, HebrewMonth::Tishrei, 29))
+            .expect("limud exists");
+        let last = MishnaYomis
+            .limud(from_hebrew_date(5778, HebrewMonth::Tishrei, 30))
+            .expect("limud exists");
+        assert_eq!(review.start, first.0);
+        assert_eq!(review.end, last.1);
+        assert_eq!(day.reviews().count(), 1);
+    }
+}
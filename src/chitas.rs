@@ -0,0 +1,218 @@
+use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth};
+
+use crate::{
+    cycle::Cycle,
+    date::{DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    location::Location,
+    parsha_hashavua::{parsha_for_shabbos, Parsha, ParshaHashavua, ParshaReading},
+    tehillim_monthly::{TehillimMonthly, TehillimUnit},
+    LimudCalculator,
+};
+
+/// The most weeks ahead to look for a parsha when the coming Shabbos is a holiday
+const MAX_HOLIDAY_WEEKS: i32 = 3;
+
+/// One aliyah of the weekly parsha, learned as the Chumash of Chitas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChumashAliyah {
+    /// The parsha, or both parshiyos when they are combined
+    pub parsha: ParshaReading,
+    /// The aliyah, from 1 (rishon) to 7 (shvi'i)
+    pub aliyah: u8,
+}
+
+/// The Chumash and Tehillim of Chitas for one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChitasUnit {
+    /// The day's aliyah of the weekly parsha
+    pub chumash: ChumashAliyah,
+    /// The day's portion of the monthly Tehillim
+    pub tehillim: Option<TehillimUnit>,
+}
+
+/// Calculates the daily aliyah of the weekly parsha, with Rashi.
+///
+/// Aliyos are identified by their number; their verse ranges are not included yet.
+///
+/// Sunday learns the first aliyah of the parsha read on the coming Shabbos, and Shabbos
+/// learns the seventh. When the coming Shabbos is a holiday, the parsha of the following
+/// Shabbos is learned, and from Sukkos until Simchas Torah, Vezos Haberacha is learned.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{ChumashYomi, LimudCalendar, Location, Parsha, ParshaReading};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// // 2024-10-29 is the Tuesday after Shabbos Bereishis
+/// let date = Date::try_new_iso(2024, 10, 29).unwrap().to_calendar(Hebrew);
+/// let aliyah = date.limud(ChumashYomi::new(Location::Diaspora)).unwrap();
+/// assert_eq!(aliyah.parsha, ParshaReading::Single(Parsha::Noach));
+/// assert_eq!(aliyah.aliyah, 3);
+/// ```
+#[derive(Default)]
+pub struct ChumashYomi {
+    /// Whether the calculator is for Israel or the diaspora
+    pub location: Location,
+}
+
+impl ChumashYomi {
+    /// Create a new Chumash calculator.
+    ///
+    /// # Arguments
+    /// * `location` - Whether the calculator is for Israel or the diaspora
+    ///
+    /// # Returns
+    /// A new Chumash calculator.
+    pub const fn new(location: Location) -> Self {
+        Self { location }
+    }
+
+    /// The parsha learned during the week that ends on the given Shabbos
+    fn parsha_for_week(&self, shabbos: HebrewDate, limud_date: &HebrewDate) -> Option<ParshaReading> {
+        // From Sukkos through Simchas Torah
+        if limud_date.hebrew_month() == HebrewMonth::Tishrei
+            && limud_date.day_of_month().0 >= 15
+            && (limud_date.is_yom_tov(self.location) || limud_date.is_chol_hamoed(self.location))
+        {
+            return Some(ParshaReading::Single(Parsha::VezosHaberacha));
+        }
+        let mut shabbos = shabbos;
+        for _ in 0..MAX_HOLIDAY_WEEKS {
+            if let Some(parsha) = parsha_for_shabbos(shabbos, self.location) {
+                return Some(parsha);
+            }
+            if shabbos.hebrew_month() == HebrewMonth::Tishrei && shabbos.day_of_month().0 >= 15 {
+                return Some(ParshaReading::Single(Parsha::VezosHaberacha));
+            }
+            shabbos = shabbos.add_days(7)?;
+        }
+        None
+    }
+}
+
+impl InternalLimudCalculator<ChumashAliyah> for ChumashYomi {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Perpetual(ParshaHashavua::find_yearly_cycle)
+    }
+
+    fn interval_end_calculation(_cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
+        // Each interval is a week, ending on Shabbos
+        hebrew_date.add_days(7 - hebrew_date.day_of_week_number())
+    }
+
+    fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<ChumashAliyah> {
+        Some(ChumashAliyah {
+            parsha: self.parsha_for_week(interval.end_date, limud_date)?,
+            aliyah: limud_date.day_of_week_number() as u8,
+        })
+    }
+}
+impl LimudCalculator<ChumashAliyah> for ChumashYomi {}
+
+/// Calculates the daily Chitas: the Chumash of [`ChumashYomi`] and the Tehillim of
+/// [`TehillimMonthly`].
+///
+/// The daily Tanya is not included yet, since its yearly division is not part of this crate.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{Chitas, LimudCalendar, Location, TehillimUnit};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let date = Date::try_new_iso(2024, 10, 29).unwrap().to_calendar(Hebrew);
+/// let chitas = date.limud(Chitas::new(Location::Diaspora)).unwrap();
+/// assert_eq!(chitas.chumash.aliyah, 3);
+/// assert!(matches!(chitas.tehillim, Some(TehillimUnit::Psalms { .. })));
+/// ```
+#[derive(Default)]
+pub struct Chitas {
+    /// The Chumash calculator
+    pub chumash: ChumashYomi,
+}
+
+impl Chitas {
+    /// Create a new Chitas calculator.
+    ///
+    /// # Arguments
+    /// * `location` - Whether the calculator is for Israel or the diaspora
+    ///
+    /// # Returns
+    /// A new Chitas calculator.
+    pub const fn new(location: Location) -> Self {
+        Self {
+            chumash: ChumashYomi::new(location),
+        }
+    }
+}
+
+impl InternalLimudCalculator<ChitasUnit> for Chitas {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Perpetual(ParshaHashavua::find_yearly_cycle)
+    }
+
+    fn interval_end_calculation(cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
+        <ChumashYomi as InternalLimudCalculator<ChumashAliyah>>::interval_end_calculation(cycle, hebrew_date)
+    }
+
+    fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<ChitasUnit> {
+        Some(ChitasUnit {
+            chumash: self.chumash.unit_for_interval(interval, limud_date)?,
+            tehillim: TehillimMonthly.limud(*limud_date),
+        })
+    }
+}
+impl LimudCalculator<ChitasUnit> for Chitas {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::date::from_gregorian_date;
+
+    use super::*;
+
+    fn chumash(year: i32, month: u8, day: u8, location: Location) -> Option<ChumashAliyah> {
+        ChumashYomi::new(location).limud(from_gregorian_date(year, month, day))
+    }
+
+    #[test]
+    fn chumash_aliyah_by_weekday() {
+        // 2024-10-27 is Sunday, 2024-11-02 is Shabbos
+        let sunday = chumash(2024, 10, 27, Location::Diaspora).expect("limud exists");
+        assert_eq!(sunday.parsha, ParshaReading::Single(Parsha::Noach));
+        assert_eq!(sunday.aliyah, 1);
+        let shabbos = chumash(2024, 11, 2, Location::Diaspora).expect("limud exists");
+        assert_eq!(shabbos.parsha, ParshaReading::Single(Parsha::Noach));
+        assert_eq!(shabbos.aliyah, 7);
+    }
+
+    #[test]
+    fn chumash_holiday_week_learns_next_parsha() {
+        // Shabbos 2025-04-19 is Chol Hamoed Pesach, so the week learns Shemini
+        let aliyah = chumash(2025, 4, 15, Location::Diaspora).expect("limud exists");
+        assert_eq!(aliyah.parsha, ParshaReading::Single(Parsha::Shemini));
+        assert_eq!(aliyah.aliyah, 3);
+    }
+
+    #[test]
+    fn chumash_vezos_haberacha() {
+        // Simchas Torah 5785 was Friday 2024-10-25 outside Israel
+        let sukkos = chumash(2024, 10, 20, Location::Diaspora).expect("limud exists");
+        assert_eq!(sukkos.parsha, ParshaReading::Single(Parsha::VezosHaberacha));
+        let simchas_torah = chumash(2024, 10, 25, Location::Diaspora).expect("limud exists");
+        assert_eq!(simchas_torah.parsha, ParshaReading::Single(Parsha::VezosHaberacha));
+        let israel = chumash(2024, 10, 25, Location::Israel).expect("limud exists");
+        assert_eq!(israel.parsha, ParshaReading::Single(Parsha::Bereishis));
+    }
+
+    #[test]
+    fn chitas_includes_tehillim() {
+        let date = from_gregorian_date(2024, 10, 29);
+        let chitas = Chitas::new(Location::Diaspora).limud(date).expect("limud exists");
+        assert_eq!(Some(chitas.chumash), ChumashYomi::new(Location::Diaspora).limud(date));
+        assert_eq!(chitas.tehillim, TehillimMonthly.limud(date));
+    }
+}
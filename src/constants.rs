@@ -12,8 +12,23 @@ pub const BAVLI_DAF_COUNT_MODERN: i32 = 2711;
 /// Number of dafim in the Yerushalmi Talmud
 pub const YERUSHALMI_DAF_COUNT: i32 = 1554;
 
+/// Number of mishnayos in Shas
+pub const MISHNA_COUNT: i32 = 4192;
+
 /// Number of days in a Mishna Yomis cycle (4192 mishnas / 2 per day)
 pub const MISHNA_YOMIS_CYCLE_DAYS: i32 = 2095;
 
+/// Number of chapters in Tanach
+pub const TANACH_CHAPTER_COUNT: i32 = 929;
+
+/// Number of chapters in Nevi'im and Kesuvim
+pub const NACH_CHAPTER_COUNT: i32 = 742;
+
+/// Number of units in a Rambam Yomi cycle (17 parts of the introduction and 1000 chapters)
+pub const RAMBAM_UNIT_COUNT: i32 = 1017;
+
+/// Number of parts the Rambam's introduction and list of mitzvos are divided into
+pub const RAMBAM_INTRODUCTION_UNITS: i32 = 17;
+
 /// Cycle number at which Shekalim expanded from 13 to 22 pages
 pub const SHEKALIM_EXPANSION_CYCLE: i32 = 8;
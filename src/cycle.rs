@@ -1,5 +1,58 @@
 use crate::{date::*, limud_calculator::PerpetualCycleFinder};
 pub type CycleEndCalculation = fn(HebrewDate, Option<i32>) -> Option<HebrewDate>;
+pub type CycleUnitCount = fn(i32) -> Option<i32>;
+
+/// A single numbered cycle of a schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CycleInfo {
+    /// The cycle number, starting at 1 for the schedule's first cycle
+    pub number: i32,
+    /// The first day of the cycle
+    #[cfg_attr(feature = "defmt", defmt(Debug2Format))]
+    pub start_date: HebrewDate,
+    /// The last day of the cycle (the siyum)
+    #[cfg_attr(feature = "defmt", defmt(Debug2Format))]
+    pub end_date: HebrewDate,
+    /// The number of units learned in the cycle
+    pub unit_count: i32,
+}
+
+/// Iterator over the cycles of a schedule, starting from its first cycle.
+///
+/// The iterator does not end, so future cycles can be listed as well.
+pub struct Cycles {
+    next: Option<Cycle>,
+    cycle_end_calculation: CycleEndCalculation,
+    unit_count: CycleUnitCount,
+}
+impl Cycles {
+    pub(crate) fn new(
+        first: Option<Cycle>,
+        cycle_end_calculation: CycleEndCalculation,
+        unit_count: CycleUnitCount,
+    ) -> Self {
+        Self {
+            next: first,
+            cycle_end_calculation,
+            unit_count,
+        }
+    }
+}
+impl Iterator for Cycles {
+    type Item = CycleInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        let cycle = self.next.take()?;
+        let number = cycle.iteration?;
+        self.next = cycle.next(self.cycle_end_calculation);
+        Some(CycleInfo {
+            number,
+            start_date: cycle.start_date,
+            end_date: cycle.end_date,
+            unit_count: (self.unit_count)(number)?,
+        })
+    }
+}
 
 #[derive(Clone, Copy)]
 pub struct Cycle {
@@ -1,7 +1,9 @@
 use core::ops::RangeInclusive;
 
 use crate::{
+    annotations::{annotate_tractate_bounds, Annotations},
     constants::BAVLI_DAF_COUNT_MODERN,
+    cycle::Cycle,
     date::{from_gregorian_date, DateExt, HebrewDate},
     interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
@@ -89,7 +91,7 @@ fn iter_daf(iteration: i32) -> impl Iterator<Item = Daf> {
 pub struct DafHashavuaBavli {}
 
 impl InternalLimudCalculator<Daf> for DafHashavuaBavli {
-    fn interval_end_calculation(_cycle: crate::cycle::Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
+    fn interval_end_calculation(_cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
         let day_number = hebrew_date.day_of_week_number();
         hebrew_date.add_days(7 - day_number)
     }
@@ -100,12 +102,36 @@ impl InternalLimudCalculator<Daf> for DafHashavuaBavli {
         let day_number = hebrew_date.day_of_week_number();
         hebrew_date.add_days((BAVLI_DAF_COUNT_MODERN * 7) - day_number)
     }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(BAVLI_DAF_COUNT_MODERN)
+    }
 
     fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<Daf> {
         let cycle_iteration = interval.cycle.iteration?;
         let mut iter = iter_daf(cycle_iteration);
         iter.nth((interval.iteration - 1) as usize)
     }
+    fn tractates(&self) -> &'static [Tractate] {
+        &BAVLI_TRACTATES
+    }
+    fn tractate_unit_count(tractate: Tractate, iteration: i32) -> i32 {
+        iter(tractate, iteration).count() as i32
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.iteration)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        if index == 0 {
+            return Some(cycle.start_date);
+        }
+        // Every week after the first begins on Sunday
+        let second_week = cycle.start_date.add_days(8 - cycle.start_date.day_of_week_number())?;
+        second_week.add_days((index - 1) * 7)
+    }
+
+    fn annotate(&self, unit: &Daf, interval: &Interval, annotations: &mut Annotations) {
+        annotate_tractate_bounds(unit.tractate, unit.page, interval, start_daf, end_daf, annotations);
+    }
 }
 
 impl LimudCalculator<Daf> for DafHashavuaBavli {}
@@ -146,4 +172,12 @@ mod tests {
         assert_eq!(limud.page, 73);
         assert_eq!(limud.tractate, Tractate::Niddah);
     }
+
+    #[test]
+    fn daf_hashavua_bavli_cycles() {
+        let first = DafHashavuaBavli::default().cycles().next().expect("first cycle exists");
+        assert_eq!(first.number, 1);
+        assert_eq!(first.start_date, from_gregorian_date(2005, 3, 6));
+        assert_eq!(first.unit_count, BAVLI_DAF_COUNT_MODERN);
+    }
 }
@@ -3,7 +3,9 @@ use core::ops::RangeInclusive;
 use icu_calendar::options::DateDifferenceOptions;
 
 use crate::{
+    annotations::{annotate_tractate_bounds, Annotations},
     constants::{BAVLI_DAF_COUNT_EARLY, BAVLI_DAF_COUNT_MODERN, SHEKALIM_EXPANSION_CYCLE},
+    cycle::Cycle,
     date::{from_gregorian_date, DateExt, HebrewDate},
     interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
@@ -87,7 +89,7 @@ pub const fn iter(tractate: Tractate, iteration: i32) -> RangeInclusive<u16> {
         RangeInclusive::new(1, 0)
     }
 }
-fn iter_daf(iteration: i32) -> impl Iterator<Item = Daf> {
+pub(crate) fn iter_daf(iteration: i32) -> impl Iterator<Item = Daf> {
     BAVLI_TRACTATES
         .iter()
         .flat_map(move |i| iter(*i, iteration).map(move |j| Daf { tractate: *i, page: j }))
@@ -113,6 +115,13 @@ impl InternalLimudCalculator<Daf> for DafYomiBavli {
             .unwrap_or(BAVLI_DAF_COUNT_EARLY);
         hebrew_date.add_days(days - 1)
     }
+    fn cycle_unit_count(iteration: i32) -> Option<i32> {
+        if iteration < SHEKALIM_EXPANSION_CYCLE {
+            Some(BAVLI_DAF_COUNT_EARLY)
+        } else {
+            Some(BAVLI_DAF_COUNT_MODERN)
+        }
+    }
 
     fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<Daf> {
         let cycle_iteration = interval.cycle.iteration?;
@@ -126,6 +135,22 @@ impl InternalLimudCalculator<Daf> for DafYomiBavli {
             .days;
         iter.nth(offset as usize)
     }
+    fn tractates(&self) -> &'static [Tractate] {
+        &BAVLI_TRACTATES
+    }
+    fn tractate_unit_count(tractate: Tractate, iteration: i32) -> i32 {
+        iter(tractate, iteration).count() as i32
+    }
+    fn units_completed(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.cycle.start_date.days_until(limud_date)? + 1)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index)
+    }
+
+    fn annotate(&self, unit: &Daf, interval: &Interval, annotations: &mut Annotations) {
+        annotate_tractate_bounds(unit.tractate, unit.page, interval, start_daf, end_daf, annotations);
+    }
 }
 
 impl LimudCalculator<Daf> for DafYomiBavli {}
@@ -223,4 +248,42 @@ mod tests {
         assert_eq!(limud.page, 2);
         assert_eq!(limud.tractate, Tractate::Niddah);
     }
+
+    #[test]
+    fn daf_yomi_bavli_cycles() {
+        let mut cycles = DafYomiBavli::default().cycles();
+        let first = cycles.next().expect("first cycle exists");
+        assert_eq!(first.number, 1);
+        assert_eq!(first.start_date, from_gregorian_date(1923, 9, 11));
+        assert_eq!(first.unit_count, BAVLI_DAF_COUNT_EARLY);
+        let thirteenth = cycles.nth(11).expect("cycle exists");
+        assert_eq!(thirteenth.number, 13);
+        assert_eq!(thirteenth.start_date, from_gregorian_date(2012, 8, 3));
+        assert_eq!(thirteenth.end_date, from_gregorian_date(2020, 1, 4));
+        assert_eq!(thirteenth.unit_count, BAVLI_DAF_COUNT_MODERN);
+        let fourteenth = cycles.next().expect("cycle exists");
+        assert_eq!(fourteenth.start_date, from_gregorian_date(2020, 1, 5));
+        assert_eq!(fourteenth.end_date, from_gregorian_date(2027, 6, 7));
+    }
+
+    #[test]
+    fn daf_yomi_bavli_shekalim_expansion_unit_count() {
+        let mut cycles = DafYomiBavli::default().cycles().skip(6);
+        assert_eq!(cycles.next().map(|cycle| cycle.unit_count), Some(BAVLI_DAF_COUNT_EARLY));
+        assert_eq!(
+            cycles.next().map(|cycle| cycle.unit_count),
+            Some(BAVLI_DAF_COUNT_MODERN)
+        );
+    }
+
+    #[test]
+    fn daf_yomi_bavli_cycle_for_date() {
+        let cycle = DafYomiBavli::default()
+            .cycle_for(from_gregorian_date(2017, 12, 28))
+            .expect("cycle exists");
+        assert_eq!(cycle.number, 13);
+        assert!(DafYomiBavli::default()
+            .cycle_for(from_gregorian_date(1920, 1, 1))
+            .is_none());
+    }
 }
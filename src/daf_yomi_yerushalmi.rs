@@ -1,13 +1,17 @@
 use core::ops::RangeInclusive;
 
-use hebrew_holiday_calendar::{HebrewHolidayCalendar, Holiday};
+use hebrew_holiday_calendar::Holiday;
 use icu_calendar::options::DateDifferenceOptions;
 
 use crate::{
+    annotations::{annotate_tractate_bounds, Annotations},
     constants::YERUSHALMI_DAF_COUNT,
+    cycle::Cycle,
+    daily_limudim::NoLimudReason,
     date::{from_gregorian_date, DateExt, HebrewDate},
     interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
+    location::Location,
     units::{Daf, Tractate, YERUSHALMI_TRACTATES},
     LimudCalculator,
 };
@@ -75,7 +79,7 @@ const fn iter(tractate: Tractate, iteration: i32) -> RangeInclusive<u16> {
         RangeInclusive::new(0, 0)
     }
 }
-fn iter_daf(iteration: i32) -> impl Iterator<Item = Daf> {
+pub(crate) fn iter_daf(iteration: i32) -> impl Iterator<Item = Daf> {
     YERUSHALMI_TRACTATES
         .iter()
         .flat_map(move |i| iter(*i, iteration).map(move |j| Daf { tractate: *i, page: j }))
@@ -90,15 +94,10 @@ impl InternalLimudCalculator<Daf> for DafYomiYerushalmiVilna {
     }
 
     fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
-        let mut end_date = hebrew_date.add_days(YERUSHALMI_DAF_COUNT - 1)?;
-
-        let mut found_days = found_skips_between(hebrew_date, end_date);
-        while found_days > 0 {
-            let new_start_date = end_date.add_days(1)?;
-            end_date = end_date.add_days(found_days)?;
-            found_days = found_skips_between(new_start_date, end_date);
-        }
-        Some(end_date)
+        last_learning_day(hebrew_date, YERUSHALMI_DAF_COUNT)
+    }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(YERUSHALMI_DAF_COUNT)
     }
 
     fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<Daf> {
@@ -126,6 +125,27 @@ impl InternalLimudCalculator<Daf> for DafYomiYerushalmiVilna {
     fn is_skip_interval(&self, interval: &Interval) -> bool {
         is_skip_day(&interval.start_date)
     }
+    fn skip_reason(&self, holiday: Option<NoLimudReason>) -> NoLimudReason {
+        // Only Yom Kippur and Tisha B'Av are skipped
+        holiday.unwrap_or(NoLimudReason::NotScheduled)
+    }
+    fn tractates(&self) -> &'static [Tractate] {
+        &YERUSHALMI_TRACTATES
+    }
+    fn tractate_unit_count(tractate: Tractate, iteration: i32) -> i32 {
+        iter(tractate, iteration).count() as i32
+    }
+    fn units_completed(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<i32> {
+        let start_date = interval.cycle.start_date;
+        Some(start_date.days_until(limud_date)? + 1 - found_skips_between(start_date, *limud_date))
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        last_learning_day(cycle.start_date, index + 1)
+    }
+
+    fn annotate(&self, unit: &Daf, interval: &Interval, annotations: &mut Annotations) {
+        annotate_tractate_bounds(unit.tractate, unit.page, interval, start_daf, end_daf, annotations);
+    }
 }
 
 impl LimudCalculator<Daf> for DafYomiYerushalmiVilna {}
@@ -145,6 +165,19 @@ impl Iterator for HebDateIter {
         }
     }
 }
+/// The date on which the given number of learning days, starting from `start_date`, is completed
+fn last_learning_day(start_date: HebrewDate, learning_days: i32) -> Option<HebrewDate> {
+    let mut end_date = start_date.add_days(learning_days - 1)?;
+
+    let mut found_days = found_skips_between(start_date, end_date);
+    while found_days > 0 {
+        let new_start_date = end_date.add_days(1)?;
+        end_date = end_date.add_days(found_days)?;
+        found_days = found_skips_between(new_start_date, end_date);
+    }
+    Some(end_date)
+}
+
 fn found_skips_between(a: HebrewDate, b: HebrewDate) -> i32 {
     let iter = HebDateIter { current: a, end: b };
     let mut skips = 0;
@@ -157,13 +190,15 @@ fn found_skips_between(a: HebrewDate, b: HebrewDate) -> i32 {
 }
 
 fn is_skip_day(date: &HebrewDate) -> bool {
-    date.holidays(false, false)
+    // Yom Kippur and Tisha B'Av fall on the same day in every location
+    date.holidays_in(Location::Diaspora)
         .any(|h| h == &Holiday::TishahBav || h == &Holiday::YomKippur)
 }
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
+    use hebrew_holiday_calendar::HebrewHolidayCalendar;
     use icu_calendar::{cal::Hebrew, Date};
 
     use crate::date::from_gregorian_date;
@@ -422,4 +457,15 @@ mod tests {
         assert_eq!(limud.page, 28);
         assert_eq!(limud.tractate, Tractate::AvodahZarah);
     }
+
+    #[test]
+    fn daf_yomi_yerushalmi_cycles() {
+        let mut cycles = DafYomiYerushalmiVilna::default().cycles();
+        let first = cycles.next().expect("first cycle exists");
+        assert_eq!(first.number, 1);
+        assert_eq!(first.start_date, from_gregorian_date(1980, 2, 2));
+        assert_eq!(first.unit_count, YERUSHALMI_DAF_COUNT);
+        let second = cycles.next().expect("second cycle exists");
+        assert_eq!(second.start_date, first.end_date.add_days(1).expect("date is valid"));
+    }
 }
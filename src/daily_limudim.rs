@@ -0,0 +1,368 @@
+use hebrew_holiday_calendar::Holiday;
+use icu_calendar::{cal::Hebrew, Date};
+
+use crate::{
+    any_limud::AnyLimud,
+    date::{DateExt, HebrewDate},
+    limud_calculator::InternalLimudCalculator,
+    location::Location,
+    AmudYomiBavliDirshu, Chitas, ChumashYomi, DafHashavuaBavli, DafYomiBavli, DafYomiYerushalmiVilna, MishnaYomis,
+    NachYomi, ParshaHashavua, PirkeiAvos, RambamYomi, RambamYomiOneChapter, Tanach929, TehillimMonthly,
+};
+
+const PROGRAM_COUNT: usize = 14;
+
+/// A learning program that can be included in [`DailyLimudim`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum LimudProgram {
+    DafYomiBavli,
+    DafYomiYerushalmiVilna,
+    DafHashavuaBavli,
+    AmudYomiBavliDirshu,
+    MishnaYomis,
+    PirkeiAvos,
+    TehillimMonthly,
+    RambamYomi,
+    RambamYomiOneChapter,
+    ParshaHashavua,
+    ChumashYomi,
+    Chitas,
+    NachYomi,
+    Tanach929,
+}
+
+impl LimudProgram {
+    /// Every program, in the order they are reported by [`DailyLimudim::iter`].
+    pub const ALL: [LimudProgram; PROGRAM_COUNT] = [
+        LimudProgram::DafYomiBavli,
+        LimudProgram::DafYomiYerushalmiVilna,
+        LimudProgram::DafHashavuaBavli,
+        LimudProgram::AmudYomiBavliDirshu,
+        LimudProgram::MishnaYomis,
+        LimudProgram::PirkeiAvos,
+        LimudProgram::TehillimMonthly,
+        LimudProgram::RambamYomi,
+        LimudProgram::RambamYomiOneChapter,
+        LimudProgram::ParshaHashavua,
+        LimudProgram::ChumashYomi,
+        LimudProgram::Chitas,
+        LimudProgram::NachYomi,
+        LimudProgram::Tanach929,
+    ];
+
+    const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The reason a program has no learning on a given date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum NoLimudReason {
+    /// The date is before the program's first cycle began
+    BeforeFirstCycle,
+    /// The program skips Yom Kippur
+    YomKippur,
+    /// The program skips Tisha B'Av
+    TishahBav,
+    /// The program has no learning scheduled for this date (e.g. Pirkei Avos outside the summer)
+    NotScheduled,
+}
+
+impl NoLimudReason {
+    /// The holiday on this date that programs skip, if any.
+    pub(crate) fn for_date(date: &HebrewDate, location: Location) -> Option<Self> {
+        date.holidays_in(location).find_map(Self::for_holiday)
+    }
+
+    /// The reason programs skip this holiday, if they do.
+    pub(crate) fn for_holiday(holiday: &Holiday) -> Option<Self> {
+        if holiday == &Holiday::YomKippur {
+            Some(NoLimudReason::YomKippur)
+        } else if holiday == &Holiday::TishahBav {
+            Some(NoLimudReason::TishahBav)
+        } else {
+            None
+        }
+    }
+}
+
+/// The result of a single program in [`DailyLimudim`].
+pub type ProgramLimud = Result<AnyLimud, NoLimudReason>;
+
+/// The learning of every selected program for a single date.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{AnyLimud, DailyLimudim, LimudProgram};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let date = Date::try_new_iso(2020, 1, 5).unwrap().to_calendar(Hebrew);
+/// let limudim = DailyLimudim::builder()
+///     .program(LimudProgram::DafYomiBavli)
+///     .program(LimudProgram::MishnaYomis)
+///     .build(date);
+/// assert!(matches!(limudim.get(LimudProgram::DafYomiBavli), Some(Ok(AnyLimud::Daf(_)))));
+/// assert!(limudim.get(LimudProgram::TehillimMonthly).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DailyLimudim {
+    /// The date these limudim were calculated for
+    pub date: HebrewDate,
+    /// The location the limudim were calculated for
+    pub location: Location,
+    results: [Option<ProgramLimud>; PROGRAM_COUNT],
+}
+
+impl DailyLimudim {
+    /// Calculate every program for the given date.
+    pub fn new(date: HebrewDate, location: Location) -> Self {
+        Self::builder().all_programs().location(location).build(date)
+    }
+
+    /// Create a builder to select which programs to calculate.
+    pub fn builder() -> DailyLimudimBuilder {
+        DailyLimudimBuilder::default()
+    }
+
+    /// Get the result of a single program.
+    ///
+    /// # Returns
+    /// `None` if the program was not selected, otherwise the program's limud
+    /// or the reason there is none on this date
+    pub fn get(&self, program: LimudProgram) -> Option<ProgramLimud> {
+        self.results[program.index()]
+    }
+
+    /// Iterate over the results of every selected program.
+    pub fn iter(&self) -> impl Iterator<Item = (LimudProgram, ProgramLimud)> + '_ {
+        LimudProgram::ALL
+            .iter()
+            .filter_map(move |program| self.get(*program).map(|result| (*program, result)))
+    }
+}
+
+/// Builder for [`DailyLimudim`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DailyLimudimBuilder {
+    programs: [bool; PROGRAM_COUNT],
+    location: Location,
+}
+
+impl DailyLimudimBuilder {
+    /// Include the given program.
+    pub fn program(mut self, program: LimudProgram) -> Self {
+        self.programs[program.index()] = true;
+        self
+    }
+
+    /// Include every program.
+    pub fn all_programs(mut self) -> Self {
+        self.programs = [true; PROGRAM_COUNT];
+        self
+    }
+
+    /// Set the location the limudim should be calculated for.
+    pub fn location(mut self, location: Location) -> Self {
+        self.location = location;
+        self
+    }
+
+    /// Calculate the selected programs for the given date.
+    pub fn build(self, date: HebrewDate) -> DailyLimudim {
+        let skip_reason = NoLimudReason::for_date(&date, self.location);
+        let mut results = [None; PROGRAM_COUNT];
+        for program in LimudProgram::ALL {
+            if self.programs[program.index()] {
+                results[program.index()] = Some(self.calculate(program, date, skip_reason));
+            }
+        }
+        DailyLimudim {
+            date,
+            location: self.location,
+            results,
+        }
+    }
+
+    /// Calculate the selected programs for the given Gregorian date.
+    ///
+    /// # Returns
+    /// `None` if the Gregorian date is invalid
+    pub fn build_gregorian(self, year: i32, month: u8, day: u8) -> Option<DailyLimudim> {
+        let date = Date::try_new_iso(year, month, day).ok()?.to_calendar(Hebrew);
+        Some(self.build(date))
+    }
+
+    fn calculate(&self, program: LimudProgram, date: HebrewDate, skip_reason: Option<NoLimudReason>) -> ProgramLimud {
+        match program {
+            LimudProgram::DafYomiBavli => resolve(&DafYomiBavli {}, date, skip_reason),
+            LimudProgram::DafYomiYerushalmiVilna => {
+                let calculator = DafYomiYerushalmiVilna {};
+                match skip_reason {
+                    // The holiday lookup has already been done, no need to repeat it in the calculator
+                    Some(reason) if calculator.find_cycle(date).is_some() => Err(reason),
+                    _ => resolve(&calculator, date, skip_reason),
+                }
+            }
+            LimudProgram::DafHashavuaBavli => resolve(&DafHashavuaBavli {}, date, skip_reason),
+            LimudProgram::AmudYomiBavliDirshu => resolve(&AmudYomiBavliDirshu {}, date, skip_reason),
+            LimudProgram::MishnaYomis => resolve(&MishnaYomis, date, skip_reason),
+            LimudProgram::PirkeiAvos => resolve(&PirkeiAvos::with_location(self.location), date, skip_reason),
+            LimudProgram::TehillimMonthly => resolve(&TehillimMonthly, date, skip_reason),
+            LimudProgram::RambamYomi => resolve(&RambamYomi, date, skip_reason),
+            LimudProgram::RambamYomiOneChapter => resolve(&RambamYomiOneChapter, date, skip_reason),
+            LimudProgram::ParshaHashavua => resolve(&ParshaHashavua::new(self.location), date, skip_reason),
+            LimudProgram::ChumashYomi => resolve(&ChumashYomi::new(self.location), date, skip_reason),
+            LimudProgram::Chitas => resolve(&Chitas::new(self.location), date, skip_reason),
+            LimudProgram::NachYomi => resolve(&NachYomi, date, skip_reason),
+            LimudProgram::Tanach929 => resolve(&Tanach929, date, skip_reason),
+        }
+    }
+}
+
+/// Resolve a program's limud, or the reason there is none.
+///
+/// When the program skips the date, the calculator decides whether the precomputed `skip_reason`
+/// is why, so a program that has no learning for other reasons reports [`NoLimudReason::NotScheduled`].
+fn resolve<T: Into<AnyLimud>>(
+    calculator: &impl InternalLimudCalculator<T>,
+    date: HebrewDate,
+    skip_reason: Option<NoLimudReason>,
+) -> ProgramLimud {
+    let Some(interval) = calculator.find_interval(date) else {
+        return Err(if calculator.find_cycle(date).is_none() {
+            NoLimudReason::BeforeFirstCycle
+        } else {
+            NoLimudReason::NotScheduled
+        });
+    };
+    if calculator.is_skip_interval(&interval) {
+        return Err(calculator.skip_reason(skip_reason));
+    }
+    calculator
+        .unit_for_interval(&interval, &date)
+        .map(Into::into)
+        .ok_or(NoLimudReason::NotScheduled)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+
+    use crate::{
+        date::{from_gregorian_date, from_hebrew_date},
+        Daf, PirkeiAvosUnit, Tractate,
+    };
+
+    use super::*;
+
+    #[test]
+    fn daily_limudim_all_programs() {
+        let test_date = from_gregorian_date(2017, 12, 28);
+        let limudim = DailyLimudim::new(test_date, Location::Diaspora);
+        assert_eq!(limudim.iter().count(), LimudProgram::ALL.len());
+        assert_eq!(
+            limudim.get(LimudProgram::DafYomiBavli),
+            Some(Ok(AnyLimud::Daf(Daf {
+                tractate: Tractate::Shevuos,
+                page: 30
+            })))
+        );
+        assert_eq!(
+            limudim.get(LimudProgram::AmudYomiBavliDirshu),
+            Some(Err(NoLimudReason::BeforeFirstCycle))
+        );
+        assert_eq!(
+            limudim.get(LimudProgram::PirkeiAvos),
+            Some(Err(NoLimudReason::NotScheduled))
+        );
+    }
+
+    #[test]
+    fn daily_limudim_selected_programs() {
+        let test_date = from_gregorian_date(2017, 12, 28);
+        let limudim = DailyLimudim::builder()
+            .program(LimudProgram::DafYomiYerushalmiVilna)
+            .build(test_date);
+        assert_eq!(limudim.iter().count(), 1);
+        assert!(limudim.get(LimudProgram::DafYomiBavli).is_none());
+        assert_eq!(
+            limudim.get(LimudProgram::DafYomiYerushalmiVilna),
+            Some(Ok(AnyLimud::Daf(Daf {
+                tractate: Tractate::BavaMetzia,
+                page: 33
+            })))
+        );
+    }
+
+    #[test]
+    fn daily_limudim_yom_kippur() {
+        let test_date = from_hebrew_date(5778, HebrewMonth::Tishrei, 10);
+        let limudim = DailyLimudim::new(test_date, Location::Diaspora);
+        assert_eq!(
+            limudim.get(LimudProgram::DafYomiYerushalmiVilna),
+            Some(Err(NoLimudReason::YomKippur))
+        );
+        assert!(matches!(limudim.get(LimudProgram::DafYomiBavli), Some(Ok(_))));
+    }
+
+    #[test]
+    fn daily_limudim_yom_kippur_before_first_cycle() {
+        // The Yerushalmi cycles began in 1980
+        let test_date = from_hebrew_date(5739, HebrewMonth::Tishrei, 10);
+        let limudim = DailyLimudim::new(test_date, Location::Diaspora);
+        assert_eq!(
+            limudim.get(LimudProgram::DafYomiYerushalmiVilna),
+            Some(Err(NoLimudReason::BeforeFirstCycle))
+        );
+    }
+
+    #[test]
+    fn daily_limudim_skip_reason() {
+        // Yom Kippur 5784 was on a Monday, which 929 skips as a Yom Tov
+        let test_date = from_hebrew_date(5784, HebrewMonth::Tishrei, 10);
+        let limudim = DailyLimudim::new(test_date, Location::Diaspora);
+        assert_eq!(
+            limudim.get(LimudProgram::Tanach929),
+            Some(Err(NoLimudReason::YomKippur))
+        );
+        assert!(matches!(
+            limudim.get(LimudProgram::Chitas),
+            Some(Ok(AnyLimud::Chitas(_)))
+        ));
+        // Pirkei Avos is not learned in Tishrei at all, regardless of Yom Kippur
+        assert_eq!(
+            limudim.get(LimudProgram::PirkeiAvos),
+            Some(Err(NoLimudReason::NotScheduled))
+        );
+    }
+
+    #[test]
+    fn daily_limudim_skip_reason_only_for_skipping_programs() {
+        // Tisha B'Av 5784 was on a Tuesday
+        let test_date = from_hebrew_date(5784, HebrewMonth::Av, 9);
+        let limudim = DailyLimudim::new(test_date, Location::Diaspora);
+        assert_eq!(
+            limudim.get(LimudProgram::DafYomiYerushalmiVilna),
+            Some(Err(NoLimudReason::TishahBav))
+        );
+        // 929 only skips Yom Tov, so it learns on Tisha B'Av
+        assert!(matches!(limudim.get(LimudProgram::Tanach929), Some(Ok(_))));
+    }
+
+    #[test]
+    fn daily_limudim_location() {
+        let test_date = from_hebrew_date(5778, HebrewMonth::Nissan, 22);
+        let israel = DailyLimudim::builder()
+            .program(LimudProgram::PirkeiAvos)
+            .location(Location::Israel)
+            .build(test_date);
+        assert_eq!(
+            israel.get(LimudProgram::PirkeiAvos),
+            Some(Ok(AnyLimud::PirkeiAvos(PirkeiAvosUnit::Single(1))))
+        );
+    }
+}
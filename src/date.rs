@@ -1,11 +1,13 @@
-use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth};
+use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth, Holiday};
 use icu_calendar::{
     cal::Hebrew,
-    options::DateAddOptions,
+    options::{DateAddOptions, DateDifferenceOptions},
     types::{DateDuration, Weekday},
     Date, Gregorian,
 };
 
+use crate::location::Location;
+
 pub type HebrewDate = Date<Hebrew>;
 
 #[allow(clippy::expect_used)]
@@ -20,8 +22,20 @@ pub(crate) fn from_hebrew_date(year: i32, month: HebrewMonth, day: u8) -> Hebrew
 
 pub(crate) trait DateExt {
     fn add_days(&self, days: i32) -> Option<HebrewDate>;
+    /// Adds Hebrew months, moving to the last day of the month if the day does not exist
+    fn add_months(&self, months: i32) -> Option<HebrewDate>;
+    /// Adds Hebrew years, moving to the last day of the month if the day does not exist
+    fn add_years(&self, years: i32) -> Option<HebrewDate>;
     /// Returns the day of week as a number (Sunday = 1, Saturday = 7)
     fn day_of_week_number(&self) -> i32;
+    /// Returns the holidays on this date as observed in the given location
+    fn holidays_in(&self, location: Location) -> impl Iterator<Item = &Holiday> + '_;
+    /// Returns whether this date is Yom Tov (including Rosh Hashana and Yom Kippur) in the given location
+    fn is_yom_tov(&self, location: Location) -> bool;
+    /// Returns whether this date is Chol Hamoed of Sukkos or Pesach in the given location
+    fn is_chol_hamoed(&self, location: Location) -> bool;
+    /// Returns the number of days from this date until `other` (negative if `other` is earlier)
+    fn days_until(&self, other: &HebrewDate) -> Option<i32>;
 }
 impl DateExt for HebrewDate {
     fn add_days(&self, days: i32) -> Option<HebrewDate> {
@@ -29,6 +43,16 @@ impl DateExt for HebrewDate {
         self.try_added_with_options(duration, DateAddOptions::default()).ok()
     }
 
+    fn add_months(&self, months: i32) -> Option<HebrewDate> {
+        let duration = DateDuration::for_months(months);
+        self.try_added_with_options(duration, DateAddOptions::default()).ok()
+    }
+
+    fn add_years(&self, years: i32) -> Option<HebrewDate> {
+        let duration = DateDuration::for_years(years);
+        self.try_added_with_options(duration, DateAddOptions::default()).ok()
+    }
+
     fn day_of_week_number(&self) -> i32 {
         match self.day_of_week() {
             Weekday::Sunday => 1,
@@ -40,4 +64,42 @@ impl DateExt for HebrewDate {
             Weekday::Saturday => 7,
         }
     }
+
+    fn holidays_in(&self, location: Location) -> impl Iterator<Item = &Holiday> + '_ {
+        self.holidays(location.in_israel(), false)
+            .map(|holiday| holiday as &Holiday)
+    }
+
+    fn is_yom_tov(&self, location: Location) -> bool {
+        let day = self.day_of_month().0;
+        // Yom Tov sheni is only observed outside Israel
+        let sheni = !location.in_israel();
+        match self.hebrew_month() {
+            HebrewMonth::Tishrei => matches!(day, 1 | 2 | 10 | 15 | 22) || (sheni && matches!(day, 16 | 23)),
+            HebrewMonth::Nissan => matches!(day, 15 | 21) || (sheni && matches!(day, 16 | 22)),
+            HebrewMonth::Sivan => day == 6 || (sheni && day == 7),
+            _ => false,
+        }
+    }
+
+    fn is_chol_hamoed(&self, location: Location) -> bool {
+        let day = self.day_of_month().0;
+        // Outside Israel, the second day of the festival is Yom Tov sheni
+        let first = if location.in_israel() { 16 } else { 17 };
+        match self.hebrew_month() {
+            HebrewMonth::Tishrei => (first..=21).contains(&day),
+            HebrewMonth::Nissan => (first..=20).contains(&day),
+            _ => false,
+        }
+    }
+
+    fn days_until(&self, other: &HebrewDate) -> Option<i32> {
+        if other < self {
+            return other.days_until(self).map(|days| -days);
+        }
+        let duration = self
+            .try_until_with_options(other, DateDifferenceOptions::default())
+            .ok()?;
+        Some(duration.days as i32)
+    }
 }
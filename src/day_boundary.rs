@@ -0,0 +1,377 @@
+//! Determines the Hebrew date of an instant, taking into account that a Jewish day starts at nightfall.
+//!
+//! Sunset and tzeis are calculated using the NOAA solar position algorithm.
+
+use icu_calendar::{cal::Hebrew, Date};
+use libm::{acos, asin, cos, floor, sin, tan};
+
+use crate::{
+    date::{DateExt, HebrewDate},
+    limud_calculator::InternalLimudCalculator,
+    LimudCalculator,
+};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Julian day of the Unix epoch (1970-01-01 00:00 UTC)
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
+/// Julian day of the J2000.0 epoch
+const J2000_JULIAN_DAY: f64 = 2_451_545.0;
+
+/// Zenith of the sun at sunset, accounting for refraction and the sun's radius
+const SUNSET_ZENITH: f64 = 90.833;
+
+/// A point on the earth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GeoLocation {
+    /// Latitude in degrees, positive north of the equator
+    pub latitude: f64,
+    /// Longitude in degrees, positive east of Greenwich
+    pub longitude: f64,
+}
+
+impl GeoLocation {
+    /// Create a new location from a latitude and longitude in degrees.
+    pub const fn new(latitude: f64, longitude: f64) -> Self {
+        Self { latitude, longitude }
+    }
+}
+
+/// The moment at which a new Hebrew day begins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DayBoundary {
+    /// The day begins at sunset
+    Sunset,
+    /// The day begins when the sun is the given number of degrees below the horizon (e.g. 8.5)
+    ///
+    /// In high latitude summers, when the sun never gets that low, the day begins at sunset.
+    Tzeis {
+        /// Degrees below the geometric horizon
+        degrees: f64,
+    },
+    /// The day begins a fixed number of minutes after sunset (e.g. 72)
+    MinutesAfterSunset(u16),
+}
+
+impl Default for DayBoundary {
+    fn default() -> Self {
+        DayBoundary::Tzeis { degrees: 8.5 }
+    }
+}
+
+/// Calculates the Hebrew date, and the limud, at a given instant in a given location.
+///
+/// When the sun does not set on a date, as near the poles in the summer and winter, the day
+/// begins at solar midnight instead.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{DafYomiBavli, DayBoundary, GeoLocation, HalachicDay, Tractate};
+///
+/// let new_york = HalachicDay::new(GeoLocation::new(40.7128, -74.006), DayBoundary::Sunset);
+/// // 2020-01-04 22:30 UTC is after sunset in New York, so the next day's daf is learned
+/// let daf = new_york.limud(1_578_177_000, DafYomiBavli::default()).unwrap();
+/// assert_eq!(daf.tractate, Tractate::Berachos);
+/// assert_eq!(daf.page, 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct HalachicDay {
+    /// Where the day boundary is calculated for
+    pub location: GeoLocation,
+    /// When the new day begins
+    pub boundary: DayBoundary,
+}
+
+impl HalachicDay {
+    /// Create a new calculator for the given location and day boundary.
+    pub const fn new(location: GeoLocation, boundary: DayBoundary) -> Self {
+        Self { location, boundary }
+    }
+
+    /// Calculate the Hebrew date at the given instant.
+    ///
+    /// # Arguments
+    /// * `unix_seconds` - The instant, as seconds since the Unix epoch (UTC)
+    ///
+    /// # Returns
+    /// The Hebrew date, or `None` if the instant is outside the supported date range
+    pub fn hebrew_date(&self, unix_seconds: i64) -> Option<HebrewDate> {
+        // Local mean solar time decides which civil date the instant falls on
+        let local_seconds = unix_seconds + (self.location.longitude * 240.0) as i64;
+        let civil_day = local_seconds.div_euclid(SECONDS_PER_DAY);
+        let boundary = self.boundary_unix_seconds(civil_day)?;
+        let (year, month, day) = civil_from_days(civil_day);
+        let date = Date::try_new_iso(year, month, day).ok()?.to_calendar(Hebrew);
+        if unix_seconds >= boundary {
+            date.add_days(1)
+        } else {
+            Some(date)
+        }
+    }
+
+    /// Calculate the limud at the given instant.
+    ///
+    /// # Arguments
+    /// * `unix_seconds` - The instant, as seconds since the Unix epoch (UTC)
+    /// * `limud_calculator` - A calculator implementing the `LimudCalculator` trait
+    pub fn limud<T>(&self, unix_seconds: i64, limud_calculator: impl LimudCalculator<T>) -> Option<T> {
+        InternalLimudCalculator::limud(&limud_calculator, self.hebrew_date(unix_seconds)?)
+    }
+
+    /// Calculate the instant the next Hebrew day begins on the given civil date.
+    ///
+    /// # Returns
+    /// Seconds since the Unix epoch (UTC), or `None` if the date is invalid
+    pub fn boundary_on(&self, year: i32, month: u8, day: u8) -> Option<i64> {
+        Date::try_new_iso(year, month, day).ok()?;
+        self.boundary_unix_seconds(days_from_civil(year, month, day))
+    }
+
+    fn boundary_unix_seconds(&self, civil_day: i64) -> Option<i64> {
+        let julian_day = civil_day as f64 + UNIX_EPOCH_JULIAN_DAY;
+        let GeoLocation { latitude, longitude } = self.location;
+        let sunset = sunset_utc_minutes(julian_day, latitude, longitude, SUNSET_ZENITH);
+        let minutes = match self.boundary {
+            DayBoundary::Sunset => sunset,
+            DayBoundary::Tzeis { degrees } => {
+                sunset_utc_minutes(julian_day, latitude, longitude, 90.0 + degrees).or(sunset)
+            }
+            DayBoundary::MinutesAfterSunset(offset) => sunset.map(|sunset| sunset + offset as f64),
+        }
+        .unwrap_or_else(|| solar_midnight_utc_minutes(julian_day, longitude));
+        Some(civil_day * SECONDS_PER_DAY + (minutes * 60.0) as i64)
+    }
+}
+
+/// Minutes after 00:00 UTC of the given Julian day at which the sun sets to the given zenith.
+fn sunset_utc_minutes(julian_day: f64, latitude: f64, longitude: f64, zenith: f64) -> Option<f64> {
+    // Start at local solar noon and refine using the sun's position at the previous estimate
+    let mut minutes = 720.0 - 4.0 * longitude;
+    for _ in 0..3 {
+        let t = julian_centuries(julian_day + minutes / 1440.0);
+        let hour_angle = sunset_hour_angle(latitude, sun_declination(t), zenith)?;
+        minutes = 720.0 - 4.0 * longitude - equation_of_time(t) + 4.0 * hour_angle.to_degrees();
+    }
+    Some(minutes)
+}
+
+/// Minutes after 00:00 UTC of the given Julian day of the solar midnight that ends it.
+fn solar_midnight_utc_minutes(julian_day: f64, longitude: f64) -> f64 {
+    let minutes = 1440.0 - 4.0 * longitude;
+    minutes - equation_of_time(julian_centuries(julian_day + minutes / 1440.0))
+}
+
+fn julian_centuries(julian_day: f64) -> f64 {
+    (julian_day - J2000_JULIAN_DAY) / 36525.0
+}
+
+fn geom_mean_longitude_sun(t: f64) -> f64 {
+    let longitude = 280.46646 + t * (36000.76983 + 0.0003032 * t);
+    longitude - 360.0 * floor(longitude / 360.0)
+}
+
+fn geom_mean_anomaly_sun(t: f64) -> f64 {
+    357.52911 + t * (35999.05029 - 0.0001537 * t)
+}
+
+fn eccentricity_earth_orbit(t: f64) -> f64 {
+    0.016708634 - t * (0.000042037 + 0.0000001267 * t)
+}
+
+fn sun_equation_of_center(t: f64) -> f64 {
+    let m = geom_mean_anomaly_sun(t).to_radians();
+    sin(m) * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + sin(2.0 * m) * (0.019993 - 0.000101 * t)
+        + sin(3.0 * m) * 0.000289
+}
+
+fn sun_apparent_longitude(t: f64) -> f64 {
+    let true_longitude = geom_mean_longitude_sun(t) + sun_equation_of_center(t);
+    let omega = 125.04 - 1934.136 * t;
+    true_longitude - 0.00569 - 0.00478 * sin(omega.to_radians())
+}
+
+fn obliquity_correction(t: f64) -> f64 {
+    let seconds = 21.448 - t * (46.8150 + t * (0.00059 - t * 0.001813));
+    let mean_obliquity = 23.0 + (26.0 + seconds / 60.0) / 60.0;
+    let omega = 125.04 - 1934.136 * t;
+    mean_obliquity + 0.00256 * cos(omega.to_radians())
+}
+
+/// Declination of the sun, in radians
+fn sun_declination(t: f64) -> f64 {
+    let epsilon = obliquity_correction(t).to_radians();
+    let lambda = sun_apparent_longitude(t).to_radians();
+    asin(sin(epsilon) * sin(lambda))
+}
+
+/// Difference between true and mean solar time, in minutes
+fn equation_of_time(t: f64) -> f64 {
+    let epsilon = obliquity_correction(t).to_radians();
+    let l0 = geom_mean_longitude_sun(t).to_radians();
+    let e = eccentricity_earth_orbit(t);
+    let m = geom_mean_anomaly_sun(t).to_radians();
+    let y = tan(epsilon / 2.0) * tan(epsilon / 2.0);
+    let equation = y * sin(2.0 * l0) - 2.0 * e * sin(m) + 4.0 * e * y * sin(m) * cos(2.0 * l0)
+        - 0.5 * y * y * sin(4.0 * l0)
+        - 1.25 * e * e * sin(2.0 * m);
+    4.0 * equation.to_degrees()
+}
+
+/// Hour angle of the sun at sunset, in radians
+fn sunset_hour_angle(latitude: f64, declination: f64, zenith: f64) -> Option<f64> {
+    let latitude = latitude.to_radians();
+    let cos_hour_angle =
+        cos(zenith.to_radians()) / (cos(latitude) * cos(declination)) - tan(latitude) * tan(declination);
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+    Some(acos(cos_hour_angle))
+}
+
+/// Convert days since the Unix epoch to a proleptic Gregorian date.
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u8, day as u8)
+}
+
+/// Convert a proleptic Gregorian date to days since the Unix epoch.
+fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{date::from_gregorian_date, DafYomiBavli, Tractate};
+
+    use super::*;
+
+    const JERUSALEM: GeoLocation = GeoLocation::new(31.778, 35.235);
+    const NEW_YORK: GeoLocation = GeoLocation::new(40.7128, -74.006);
+
+    /// 2024-06-21 00:00 UTC
+    const SUMMER_SOLSTICE_2024: i64 = 1_718_928_000;
+
+    #[test]
+    fn civil_date_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(days_from_civil(2024, 6, 21), SUMMER_SOLSTICE_2024 / SECONDS_PER_DAY);
+        assert_eq!(civil_from_days(days_from_civil(1923, 9, 11)), (1923, 9, 11));
+        assert_eq!(civil_from_days(days_from_civil(2000, 2, 29)), (2000, 2, 29));
+    }
+
+    #[test]
+    fn sunset_in_jerusalem() {
+        // Sunset in Jerusalem on 2024-06-21 is at about 19:47 local time (16:47 UTC)
+        let day = HalachicDay::new(JERUSALEM, DayBoundary::Sunset);
+        let sunset = day.boundary_on(2024, 6, 21).expect("sun sets");
+        let expected = SUMMER_SOLSTICE_2024 + 16 * 3600 + 47 * 60;
+        assert!((sunset - expected).abs() < 120, "sunset at {}", sunset);
+    }
+
+    #[test]
+    fn tzeis_is_after_sunset() {
+        let sunset = HalachicDay::new(JERUSALEM, DayBoundary::Sunset)
+            .boundary_on(2024, 6, 21)
+            .expect("sun sets");
+        let tzeis = HalachicDay::new(JERUSALEM, DayBoundary::Tzeis { degrees: 8.5 })
+            .boundary_on(2024, 6, 21)
+            .expect("sun sets");
+        let fixed = HalachicDay::new(JERUSALEM, DayBoundary::MinutesAfterSunset(72))
+            .boundary_on(2024, 6, 21)
+            .expect("sun sets");
+        assert!(tzeis > sunset + 20 * 60 && tzeis < sunset + 50 * 60);
+        assert_eq!(fixed, sunset + 72 * 60);
+    }
+
+    #[test]
+    fn hebrew_date_changes_at_boundary() {
+        let day = HalachicDay::new(JERUSALEM, DayBoundary::Sunset);
+        let civil = from_gregorian_date(2024, 6, 21);
+        let before = day.hebrew_date(SUMMER_SOLSTICE_2024 + 16 * 3600).expect("date exists");
+        let after = day.hebrew_date(SUMMER_SOLSTICE_2024 + 17 * 3600).expect("date exists");
+        assert_eq!(before, civil);
+        assert_eq!(after, civil.add_days(1).expect("date exists"));
+    }
+
+    #[test]
+    fn hebrew_date_after_local_midnight() {
+        // 2024-06-21 22:00 UTC is after midnight in Jerusalem on the 22nd, before sunset
+        let day = HalachicDay::new(JERUSALEM, DayBoundary::Sunset);
+        let date = day.hebrew_date(SUMMER_SOLSTICE_2024 + 22 * 3600).expect("date exists");
+        assert_eq!(date, from_gregorian_date(2024, 6, 22));
+    }
+
+    #[test]
+    fn tzeis_falls_back_to_sunset() {
+        // The sun does not reach 8.5 degrees below the horizon in Stockholm at the solstice,
+        // but sets at about 22:08 local time (20:08 UTC)
+        let stockholm = GeoLocation::new(59.3293, 18.0686);
+        let tzeis = HalachicDay::new(stockholm, DayBoundary::default());
+        let sunset = HalachicDay::new(stockholm, DayBoundary::Sunset)
+            .boundary_on(2024, 6, 21)
+            .expect("sun sets");
+        assert_eq!(tzeis.boundary_on(2024, 6, 21), Some(sunset));
+        let civil = from_gregorian_date(2024, 6, 21);
+        let before = tzeis
+            .hebrew_date(SUMMER_SOLSTICE_2024 + 19 * 3600)
+            .expect("date exists");
+        let after = tzeis
+            .hebrew_date(SUMMER_SOLSTICE_2024 + 21 * 3600)
+            .expect("date exists");
+        assert_eq!(before, civil);
+        assert_eq!(after, civil.add_days(1).expect("date exists"));
+    }
+
+    #[test]
+    fn solar_midnight_in_polar_summer() {
+        // The sun does not set in Svalbard at the solstice, so the day begins at solar midnight,
+        // about 22:59 UTC
+        let svalbard = HalachicDay::new(GeoLocation::new(78.22, 15.65), DayBoundary::Sunset);
+        let midnight = svalbard.boundary_on(2024, 6, 21).expect("valid date");
+        let expected = SUMMER_SOLSTICE_2024 + 22 * 3600 + 59 * 60;
+        assert!((midnight - expected).abs() < 120, "solar midnight at {}", midnight);
+        let civil = from_gregorian_date(2024, 6, 21);
+        assert_eq!(svalbard.hebrew_date(SUMMER_SOLSTICE_2024 + 12 * 3600), Some(civil));
+        assert_eq!(
+            svalbard.hebrew_date(SUMMER_SOLSTICE_2024 + 23 * 3600 + 30 * 60),
+            civil.add_days(1)
+        );
+    }
+
+    #[test]
+    fn daf_yomi_after_sunset() {
+        // Sunset in New York on 2020-01-04 is at about 16:40 EST (21:40 UTC)
+        let day = HalachicDay::new(NEW_YORK, DayBoundary::Sunset);
+        let afternoon = day
+            .limud(1_578_096_000 + 20 * 3600, DafYomiBavli::default())
+            .expect("limud exists");
+        assert_eq!(afternoon.tractate, Tractate::Niddah);
+        assert_eq!(afternoon.page, 73);
+        let evening = day
+            .limud(1_578_096_000 + 22 * 3600, DafYomiBavli::default())
+            .expect("limud exists");
+        assert_eq!(evening.tractate, Tractate::Berachos);
+        assert_eq!(evening.page, 2);
+    }
+}
@@ -6,6 +6,11 @@
 //! - Amud Yomi Bavli Dirshu (Babylonian Talmud daily column - Dirshu schedule)
 //! - Daf Hashavua Bavli (Babylonian Talmud weekly page)
 //! - Mishna Yomis (Daily Mishna)
+//! - Rambam Yomi (Mishneh Torah, three chapters or one chapter a day)
+//! - Parashas Hashavua (Weekly Torah portion)
+//! - Chitas (Chumash with Rashi by aliyah, with the monthly Tehillim)
+//! - Nach Yomi (one chapter of Nevi'im and Kesuvim a day)
+//! - 929 (one chapter of Tanach each weekday from Sunday to Thursday)
 //! - Pirkei Avos (Ethics of the Fathers - seasonal schedule)
 //! - Tehillim Monthly (Monthly Psalms reading)
 //!
@@ -24,19 +29,44 @@
 
 use icu_calendar::{cal::Hebrew, Date};
 
+use crate::annotations::annotated_limud;
+
 mod amud_yomi_bavli_dirshu;
+mod annotations;
+mod any_limud;
+mod catch_up;
+mod chaluka;
+mod chazara;
+mod chitas;
 mod constants;
 mod cycle;
 mod daf_hashavua_bavli;
 mod daf_yomi_bavli;
 mod daf_yomi_yerushalmi;
+mod daily_limudim;
 mod date;
+mod day_boundary;
 mod interval;
 mod limud_calculator;
+mod location;
 mod mishna_yomis;
+mod mivchan;
+mod nach_yomi;
+mod pace_planner;
+mod parsha_hashavua;
 mod pirkei_avos;
+mod progress;
+mod rambam_yomi;
+mod registry;
+mod sequence;
+mod skip_rules;
+mod tanach;
+mod tanach_929;
 mod tehillim_monthly;
 mod units;
+mod weekday_schedule;
+mod yahrzeit;
+mod zman_calendar;
 
 /// Extension trait for Hebrew dates to calculate limud schedules.
 ///
@@ -52,23 +82,76 @@ pub trait LimudCalendar {
     /// # Returns
     /// The learning unit for this date, or `None` if no learning is scheduled
     fn limud<T>(&self, limud_calculator: impl LimudCalculator<T>) -> Option<T>;
+
+    /// Calculate the limud for this date along with holiday and schedule annotations.
+    ///
+    /// # Arguments
+    /// * `limud_calculator` - A calculator implementing the `LimudCalculator` trait
+    /// * `location` - The location used to look up holidays
+    ///
+    /// # Returns
+    /// The learning unit for this date, if any, and its annotations
+    fn annotated_limud<T>(&self, limud_calculator: impl LimudCalculator<T>, location: Location) -> AnnotatedLimud<T>;
 }
 impl LimudCalendar for Date<Hebrew> {
     fn limud<T>(&self, limud_calculator: impl LimudCalculator<T>) -> Option<T> {
         limud_calculator.limud(*self)
     }
+
+    fn annotated_limud<T>(&self, limud_calculator: impl LimudCalculator<T>, location: Location) -> AnnotatedLimud<T> {
+        annotated_limud(&limud_calculator, *self, location)
+    }
 }
 // Calculators
 pub use amud_yomi_bavli_dirshu::AmudYomiBavliDirshu;
+pub use chitas::{Chitas, ChitasUnit, ChumashAliyah, ChumashYomi};
 pub use daf_hashavua_bavli::DafHashavuaBavli;
 pub use daf_yomi_bavli::DafYomiBavli;
 pub use daf_yomi_yerushalmi::DafYomiYerushalmiVilna;
 pub use mishna_yomis::{MishnaYomis, Mishnas};
+pub use nach_yomi::NachYomi;
+pub use parsha_hashavua::{Parsha, ParshaHashavua, ParshaReading, ALL_PARSHIYOS};
 pub use pirkei_avos::{PirkeiAvos, PirkeiAvosUnit};
+pub use rambam_yomi::{Halachos, RambamChapters, RambamUnit, RambamYomi, RambamYomiOneChapter, Sefer, ALL_HALACHOS};
+pub use tanach_929::{Tanach929, Tanach929Unit};
 pub use tehillim_monthly::{TehillimMonthly, TehillimUnit};
 
 // Unit types
-pub use units::{Amud, Daf, Mishna, Side, Tractate};
+pub use tanach::{TanachBook, TanachChapter, TanachSection, ALL_TANACH_BOOKS};
+pub use units::{Amud, Daf, Mishna, Seder, Side, Tractate};
+
+// Yahrzeit
+pub use yahrzeit::yahrzeit;
+
+// Type-erased calculators
+pub use any_limud::{AnyLimud, DynLimudCalculator};
+pub use registry::{LimudRegistry, SharedLimudCalculator};
+
+// Combined lookups
+pub use annotations::{AnnotatedLimud, Annotation, Annotations};
+pub use daily_limudim::{DailyLimudim, DailyLimudimBuilder, LimudProgram, NoLimudReason, ProgramLimud};
+
+// Cycles
+pub use cycle::{CycleInfo, Cycles};
+pub use progress::CycleProgress;
+
+// Planners
+pub use catch_up::{CatchUpDay, CatchUpError, CatchUpPlan, CatchUpPlanner};
+pub use chaluka::{Chaluka, ChalukaError, ChalukaShare};
+pub use chazara::{Chazara, ChazaraDay, Review, ReviewInterval};
+pub use mivchan::{CumulativeMivchan, Mivchan, MivchanRule, MivchanSchedule, MivchanScope};
+pub use pace_planner::{PacePlanner, RemainderStrategy};
+pub use sequence::{
+    BavliAmudim, BavliDafim, Mishnayos, MishnehTorah, Nach, Psalms, Tanach, UnitRange, UnitSequence, UnitSpan,
+    YerushalmiDafim,
+};
+pub use skip_rules::SkipRules;
+pub use weekday_schedule::WeekdaySchedule;
+pub use zman_calendar::{DropBeinHazmanim, Zman, ZmanCalendar, ZmanEdge, ZmanPeriod};
+
+// Location
+pub use day_boundary::{DayBoundary, GeoLocation, HalachicDay};
+pub use location::Location;
 
 // Traits
 pub use limud_calculator::LimudCalculator;
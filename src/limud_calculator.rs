@@ -1,4 +1,12 @@
-use crate::{cycle::Cycle, date::HebrewDate, interval::Interval};
+use crate::{
+    annotations::Annotations,
+    cycle::{Cycle, CycleInfo, Cycles},
+    daily_limudim::NoLimudReason,
+    date::HebrewDate,
+    interval::Interval,
+    progress::{cycle_progress, CycleProgress},
+    units::Tractate,
+};
 
 pub type PerpetualCycleFinder = fn(HebrewDate) -> (HebrewDate, HebrewDate);
 
@@ -9,6 +17,13 @@ pub enum CycleFinder {
 
 pub(crate) trait InternalLimudCalculator<T> {
     fn limud(&self, limud_date: HebrewDate) -> Option<T> {
+        let interval = self.find_interval(limud_date)?;
+        if self.is_skip_interval(&interval) {
+            return None;
+        }
+        self.unit_for_interval(&interval, &limud_date)
+    }
+    fn find_interval(&self, limud_date: HebrewDate) -> Option<Interval> {
         let cycle = self.find_cycle(limud_date)?;
         if cycle.end_date < limud_date {
             return None;
@@ -21,10 +36,7 @@ pub(crate) trait InternalLimudCalculator<T> {
                 interval.next(Self::interval_end_calculation)?
             };
         }
-        if self.is_skip_interval(&interval) {
-            return None;
-        }
-        self.unit_for_interval(&interval, &limud_date)
+        Some(interval)
     }
     fn cycle_finder(&self) -> CycleFinder;
     fn find_cycle(&self, date: HebrewDate) -> Option<Cycle> {
@@ -38,14 +50,78 @@ pub(crate) trait InternalLimudCalculator<T> {
     fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
         Some(hebrew_date)
     }
+    /// The number of units learned in the given cycle, for schedules with numbered cycles
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        None
+    }
     fn interval_end_calculation(_cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
         Some(hebrew_date)
     }
     fn is_skip_interval(&self, _interval: &Interval) -> bool {
         false
     }
+    /// Why a skip interval has no learning, given the Yom Kippur or Tisha B'Av on its date, if any
+    fn skip_reason(&self, _holiday: Option<NoLimudReason>) -> NoLimudReason {
+        NoLimudReason::NotScheduled
+    }
     fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<T>;
+    /// Add schedule-specific annotations (e.g. the start or end of a tractate) for a unit
+    fn annotate(&self, _unit: &T, _interval: &Interval, _annotations: &mut Annotations) {}
+    /// The tractates learned in each cycle, in order
+    fn tractates(&self) -> &'static [Tractate] {
+        &[]
+    }
+    /// The number of units learned in a tractate in the given cycle
+    fn tractate_unit_count(_tractate: Tractate, _iteration: i32) -> i32 {
+        0
+    }
+    /// The number of units of the cycle learned up to and including the limud date
+    fn units_completed(&self, _interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        None
+    }
+    /// The date on which the unit at the given (zero based) index of the cycle is learned
+    fn date_for_unit(&self, _cycle: &Cycle, _index: i32) -> Option<HebrewDate> {
+        None
+    }
 }
 /// Trait for calculators that can be used to calculate the limud for a given date.
 #[allow(private_bounds)]
-pub trait LimudCalculator<T>: InternalLimudCalculator<T> {}
+pub trait LimudCalculator<T>: InternalLimudCalculator<T> {
+    /// List every cycle of this schedule, from the first cycle onwards.
+    ///
+    /// The iterator does not end, so use e.g. `take_while` to stop at a given date.
+    /// Schedules without numbered cycles (e.g. Tehillim Monthly) yield no cycles.
+    fn cycles(&self) -> Cycles {
+        let first = match self.cycle_finder() {
+            CycleFinder::Initial(initial_cycle_date) => {
+                Cycle::from_cycle_initiation(initial_cycle_date, Self::cycle_end_calculation, initial_cycle_date)
+            }
+            CycleFinder::Perpetual(_) => None,
+        };
+        Cycles::new(first, Self::cycle_end_calculation, Self::cycle_unit_count)
+    }
+
+    /// Find the numbered cycle that the given date falls in.
+    ///
+    /// # Returns
+    /// The cycle, or `None` if the date is before the first cycle or the schedule has no numbered cycles
+    fn cycle_for(&self, date: HebrewDate) -> Option<CycleInfo> {
+        let cycle = self.find_cycle(date)?;
+        let number = cycle.iteration?;
+        Some(CycleInfo {
+            number,
+            start_date: cycle.start_date,
+            end_date: cycle.end_date,
+            unit_count: Self::cycle_unit_count(number)?,
+        })
+    }
+
+    /// Calculate how far into its cycle the schedule is on the given date.
+    ///
+    /// # Returns
+    /// The progress, or `None` if the date is before the first cycle or the schedule
+    /// does not learn through tractates in numbered cycles
+    fn progress(&self, date: HebrewDate) -> Option<CycleProgress> {
+        cycle_progress(self, date)
+    }
+}
@@ -0,0 +1,29 @@
+/// Where a schedule is being followed.
+///
+/// Some schedules differ between Israel and the diaspora, e.g. Pirkei Avos starts a day earlier in
+/// Israel and skips the second day of Shavuos only outside Israel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Location {
+    /// Outside of Israel, where Yom Tov sheni is observed
+    #[default]
+    Diaspora,
+    /// In Israel
+    Israel,
+}
+
+impl Location {
+    /// Create a location from an `in_israel` flag.
+    pub const fn from_in_israel(in_israel: bool) -> Self {
+        if in_israel {
+            Location::Israel
+        } else {
+            Location::Diaspora
+        }
+    }
+
+    /// Whether this location is in Israel.
+    pub const fn in_israel(self) -> bool {
+        matches!(self, Location::Israel)
+    }
+}
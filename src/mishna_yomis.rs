@@ -1,6 +1,9 @@
 use crate::{
-    constants::MISHNA_YOMIS_CYCLE_DAYS,
-    date::{from_gregorian_date, DateExt},
+    annotations::{Annotation, Annotations},
+    constants::{MISHNA_COUNT, MISHNA_YOMIS_CYCLE_DAYS},
+    cycle::Cycle,
+    date::{from_gregorian_date, DateExt, HebrewDate},
+    interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
     units::{Mishna, Tractate, ALL_TRACTATES},
     LimudCalculator,
@@ -144,7 +147,7 @@ const fn chapter_length(tractate: Tractate, chapter: usize) -> u16 {
     }
 }
 
-fn iter_mishna() -> impl Iterator<Item = Mishna> {
+pub(crate) fn iter_mishna() -> impl Iterator<Item = Mishna> {
     ALL_TRACTATES.iter().flat_map(move |t| {
         (1..=chapters(*t)).flat_map(move |c| {
             (1..=chapter_length(*t, c)).map(move |m| Mishna {
@@ -194,6 +197,36 @@ impl InternalLimudCalculator<Mishnas> for MishnaYomis {
     ) -> Option<crate::date::HebrewDate> {
         hebrew_date.add_days(MISHNA_YOMIS_CYCLE_DAYS)
     }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(MISHNA_COUNT)
+    }
+    fn tractates(&self) -> &'static [Tractate] {
+        &ALL_TRACTATES
+    }
+    fn tractate_unit_count(tractate: Tractate, _iteration: i32) -> i32 {
+        (1..=chapters(tractate))
+            .map(|chapter| chapter_length(tractate, chapter) as i32)
+            .sum()
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        // Two mishnayos are learned each day
+        Some(interval.iteration * 2)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index / 2)
+    }
+
+    fn annotate(&self, unit: &Mishnas, _interval: &Interval, annotations: &mut Annotations) {
+        for mishna in [unit.0, unit.1] {
+            if mishna.chapter == 1 && mishna.mishna == 1 {
+                annotations.push(Annotation::TractateStart(mishna.tractate));
+            }
+            let last_chapter = chapters(mishna.tractate);
+            if mishna.chapter == last_chapter && mishna.mishna == chapter_length(mishna.tractate, last_chapter) {
+                annotations.push(Annotation::Siyum(mishna.tractate));
+            }
+        }
+    }
 }
 impl LimudCalculator<Mishnas> for MishnaYomis {}
 
@@ -556,4 +589,18 @@ mod tests {
         assert_eq!(limud.1.chapter, 3);
         assert_eq!(limud.1.mishna, 3);
     }
+
+    #[test]
+    fn mishna_yomis_cycles() {
+        let mut cycles = MishnaYomis.cycles();
+        let first = cycles.next().expect("first cycle exists");
+        assert_eq!(first.number, 1);
+        assert_eq!(first.start_date, from_gregorian_date(1947, 5, 20));
+        assert_eq!(first.unit_count as usize, iter_mishna().count());
+        let cycle = MishnaYomis
+            .cycle_for(from_gregorian_date(2016, 3, 30))
+            .expect("cycle exists");
+        assert_eq!(cycle.start_date, from_gregorian_date(2016, 3, 30));
+        assert_eq!(cycle.end_date, from_gregorian_date(2021, 12, 24));
+    }
 }
@@ -0,0 +1,274 @@
+use core::marker::PhantomData;
+
+use crate::{
+    date::{DateExt, HebrewDate},
+    sequence::{UnitRange, UnitSpan},
+    units::{Seder, Tractate},
+    LimudCalculator,
+};
+
+/// How the learning of a program is divided into test (mivchan) periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MivchanRule {
+    /// One test for each Hebrew month
+    HebrewMonth,
+    /// One test for every given number of units of the program (dafim for Daf Yomi, amudim for Amud Yomi)
+    Units(u16),
+}
+
+/// What a cumulative test reviews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MivchanScope {
+    /// A single tractate
+    Tractate(Tractate),
+    /// A whole seder
+    Seder(Seder),
+}
+
+/// A test on the material learned over a period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mivchan<U> {
+    /// The first day of learning covered by the test
+    pub start_date: HebrewDate,
+    /// The last day of learning covered by the test
+    pub end_date: HebrewDate,
+    /// The units covered by the test
+    pub range: UnitRange<U>,
+}
+
+/// A cumulative test reviewing a whole tractate or seder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CumulativeMivchan<U> {
+    /// What the test reviews
+    pub scope: MivchanScope,
+    /// The test's period and units
+    pub mivchan: Mivchan<U>,
+}
+
+/// Divides the learning of a program (e.g. [`AmudYomiBavliDirshu`](crate::AmudYomiBavliDirshu)
+/// or [`DafYomiBavli`](crate::DafYomiBavli)) into test periods.
+///
+/// Test periods never cross the end of a cycle.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{AmudYomiBavliDirshu, MivchanRule, MivchanSchedule};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let schedule = MivchanSchedule::new(AmudYomiBavliDirshu::default(), MivchanRule::HebrewMonth);
+/// let date = Date::try_new_iso(2024, 1, 1).unwrap().to_calendar(Hebrew);
+/// let mivchan = schedule.mivchan(date).unwrap();
+/// assert!(mivchan.start_date <= date && date <= mivchan.end_date);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MivchanSchedule<C, T, U> {
+    calculator: C,
+    rule: MivchanRule,
+    unit: PhantomData<fn() -> (T, U)>,
+}
+
+impl<C, T, U> MivchanSchedule<C, T, U>
+where
+    C: LimudCalculator<T>,
+    T: UnitSpan<U>,
+{
+    /// Create a test schedule for the program of `calculator`.
+    pub fn new(calculator: C, rule: MivchanRule) -> Self {
+        Self {
+            calculator,
+            rule,
+            unit: PhantomData,
+        }
+    }
+
+    /// Find the test period that the given date falls in.
+    ///
+    /// # Returns
+    /// The test, or `None` if the program has no learning on this date
+    pub fn mivchan(&self, date: HebrewDate) -> Option<Mivchan<U>> {
+        let interval = self.calculator.find_interval(date)?;
+        let cycle = interval.cycle;
+        let (start_date, end_date) = match self.rule {
+            MivchanRule::HebrewMonth => {
+                let first_of_month = date.add_days(1 - date.day_of_month().0 as i32)?;
+                let last_of_month = first_of_month.add_days(date.days_in_month() as i32 - 1)?;
+                (first_of_month.max(cycle.start_date), last_of_month.min(cycle.end_date))
+            }
+            MivchanRule::Units(units) => {
+                let units = units.max(1) as i32;
+                let unit_count = C::cycle_unit_count(cycle.iteration?)?;
+                let index = self.calculator.units_completed(&interval, &date)?.max(1) - 1;
+                let first = index / units * units;
+                let last = (first + units).min(unit_count) - 1;
+                (
+                    self.calculator.date_for_unit(&cycle, first)?,
+                    self.calculator.date_for_unit(&cycle, last)?,
+                )
+            }
+        };
+        self.mivchan_between(start_date, end_date)
+    }
+
+    /// List the cumulative tests of the cycle that the given date falls in, one for each tractate.
+    pub fn tractate_reviews(&self, date: HebrewDate) -> impl Iterator<Item = CumulativeMivchan<U>> + '_ {
+        self.cumulative(date, false)
+    }
+
+    /// List the cumulative tests of the cycle that the given date falls in, one for each seder.
+    pub fn seder_reviews(&self, date: HebrewDate) -> impl Iterator<Item = CumulativeMivchan<U>> + '_ {
+        self.cumulative(date, true)
+    }
+
+    fn cumulative(&self, date: HebrewDate, per_seder: bool) -> impl Iterator<Item = CumulativeMivchan<U>> + '_ {
+        let cycle = self.calculator.find_cycle(date);
+        let iteration = cycle.as_ref().and_then(|cycle| cycle.iteration);
+        let tractates = match iteration {
+            Some(_) => self.calculator.tractates(),
+            None => &[],
+        };
+        let iteration = iteration.unwrap_or_default();
+        let mut position = 0;
+        let mut first_index = 0;
+        core::iter::from_fn(move || {
+            let cycle = cycle.as_ref()?;
+            let tractate = *tractates.get(position)?;
+            let mut count = 0;
+            while let Some(next) = tractates.get(position) {
+                if per_seder && next.seder() != tractate.seder() || !per_seder && *next != tractate {
+                    break;
+                }
+                count += C::tractate_unit_count(*next, iteration);
+                position += 1;
+            }
+            let start_date = self.calculator.date_for_unit(cycle, first_index)?;
+            let end_date = self.calculator.date_for_unit(cycle, first_index + count - 1)?;
+            first_index += count;
+            Some(CumulativeMivchan {
+                scope: if per_seder {
+                    MivchanScope::Seder(tractate.seder())
+                } else {
+                    MivchanScope::Tractate(tractate)
+                },
+                mivchan: self.mivchan_between(start_date, end_date)?,
+            })
+        })
+    }
+
+    fn mivchan_between(&self, start_date: HebrewDate, end_date: HebrewDate) -> Option<Mivchan<U>> {
+        Some(Mivchan {
+            start_date,
+            end_date,
+            range: UnitRange {
+                start: self.calculator.limud(start_date)?.first_unit(),
+                end: self.calculator.limud(end_date)?.last_unit(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        date::from_gregorian_date,
+        units::{Amud, Daf, Side},
+        AmudYomiBavliDirshu, DafYomiBavli,
+    };
+
+    use super::*;
+
+    #[test]
+    fn mivchan_hebrew_month() {
+        let schedule = MivchanSchedule::new(DafYomiBavli::default(), MivchanRule::HebrewMonth);
+        // Teves 5778 ran from 2017-12-19 to 2018-01-16
+        let mivchan = schedule
+            .mivchan(from_gregorian_date(2017, 12, 28))
+            .expect("mivchan exists");
+        assert_eq!(mivchan.start_date, from_gregorian_date(2017, 12, 19));
+        assert_eq!(mivchan.end_date, from_gregorian_date(2018, 1, 16));
+        assert_eq!(
+            mivchan.range,
+            UnitRange {
+                start: Daf {
+                    tractate: Tractate::Shevuos,
+                    page: 21
+                },
+                end: Daf {
+                    tractate: Tractate::Shevuos,
+                    page: 49
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn mivchan_clipped_to_cycle() {
+        let schedule = MivchanSchedule::new(DafYomiBavli::default(), MivchanRule::HebrewMonth);
+        let mivchan = schedule
+            .mivchan(from_gregorian_date(2020, 1, 5))
+            .expect("mivchan exists");
+        assert_eq!(mivchan.start_date, from_gregorian_date(2020, 1, 5));
+        assert_eq!(mivchan.range.start.tractate, Tractate::Berachos);
+    }
+
+    #[test]
+    fn mivchan_units() {
+        let schedule = MivchanSchedule::new(AmudYomiBavliDirshu::default(), MivchanRule::Units(40));
+        let mivchan = schedule
+            .mivchan(from_gregorian_date(2023, 10, 20))
+            .expect("mivchan exists");
+        assert_eq!(mivchan.start_date, from_gregorian_date(2023, 10, 16));
+        assert_eq!(mivchan.end_date, from_gregorian_date(2023, 11, 24));
+        assert_eq!(
+            mivchan.range.end,
+            Amud {
+                tractate: Tractate::Berachos,
+                page: 21,
+                side: Side::Bet
+            }
+        );
+        let next = schedule
+            .mivchan(from_gregorian_date(2023, 11, 25))
+            .expect("mivchan exists");
+        assert_eq!(next.start_date, from_gregorian_date(2023, 11, 25));
+    }
+
+    #[test]
+    fn mivchan_cumulative_tractates() {
+        let schedule = MivchanSchedule::new(DafYomiBavli::default(), MivchanRule::HebrewMonth);
+        let mut tractates = schedule.tractate_reviews(from_gregorian_date(2020, 1, 5));
+        let berachos = tractates.next().expect("mivchan exists");
+        assert_eq!(berachos.scope, MivchanScope::Tractate(Tractate::Berachos));
+        assert_eq!(berachos.mivchan.start_date, from_gregorian_date(2020, 1, 5));
+        assert_eq!(berachos.mivchan.end_date, from_gregorian_date(2020, 3, 7));
+        assert_eq!(tractates.count(), 39);
+    }
+
+    #[test]
+    fn mivchan_cumulative_sedarim() {
+        let schedule = MivchanSchedule::new(AmudYomiBavliDirshu::default(), MivchanRule::HebrewMonth);
+        let sedarim: [Option<CumulativeMivchan<Amud>>; 7] = {
+            let mut sedarim = schedule.seder_reviews(from_gregorian_date(2024, 1, 1));
+            core::array::from_fn(|_| sedarim.next())
+        };
+        assert_eq!(
+            sedarim[1].map(|seder| seder.scope),
+            Some(MivchanScope::Seder(Seder::Moed))
+        );
+        assert_eq!(
+            sedarim[1].map(|seder| seder.mivchan.range.start),
+            Some(Amud {
+                tractate: Tractate::Shabbos,
+                page: 2,
+                side: Side::Aleph
+            })
+        );
+        assert_eq!(
+            sedarim[5].map(|seder| seder.scope),
+            Some(MivchanScope::Seder(Seder::Taharos))
+        );
+        assert!(sedarim[6].is_none());
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    constants::NACH_CHAPTER_COUNT,
+    cycle::Cycle,
+    date::{from_gregorian_date, DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    tanach::{iter_nach, TanachChapter},
+    LimudCalculator,
+};
+
+#[derive(Default)]
+/// Calculates the Nach Yomi schedule of one chapter of Nevi'im and Kesuvim a day.
+///
+/// Each cycle is 742 days, counted from the cycle that began on 1 November 2007.
+pub struct NachYomi;
+impl InternalLimudCalculator<TanachChapter> for NachYomi {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(from_gregorian_date(2007, 11, 1))
+    }
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<TanachChapter> {
+        iter_nach().nth((interval.iteration - 1) as usize)
+    }
+    fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
+        hebrew_date.add_days(NACH_CHAPTER_COUNT - 1)
+    }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(NACH_CHAPTER_COUNT)
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.iteration)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index)
+    }
+}
+impl LimudCalculator<TanachChapter> for NachYomi {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{limud_calculator::InternalLimudCalculator, tanach::TanachBook};
+
+    use super::*;
+
+    #[test]
+    fn nach_yomi_first_day() {
+        let limud = NachYomi.limud(from_gregorian_date(2007, 11, 1));
+        assert_eq!(
+            limud,
+            Some(TanachChapter {
+                book: TanachBook::Yehoshua,
+                chapter: 1
+            })
+        );
+        assert!(NachYomi.limud(from_gregorian_date(2007, 10, 31)).is_none());
+    }
+
+    #[test]
+    fn nach_yomi_cycles() {
+        let cycle = NachYomi
+            .find_cycle(from_gregorian_date(2007, 11, 1))
+            .expect("cycle exists");
+        let last = NachYomi.limud(cycle.end_date).expect("limud exists");
+        assert_eq!(
+            last,
+            TanachChapter {
+                book: TanachBook::DivreiHaYamimII,
+                chapter: 36
+            }
+        );
+        let next = cycle.end_date.add_days(1).expect("date is valid");
+        assert_eq!(
+            NachYomi.cycle_for(next).map(|cycle| (cycle.number, cycle.start_date)),
+            Some((2, next))
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    cycle::Cycle,
+    daily_limudim::NoLimudReason,
+    date::HebrewDate,
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    sequence::{UnitRange, UnitSequence},
+    skip_rules::SkipRules,
+    LimudCalculator,
+};
+
+/// How units that do not divide evenly over the learning days are assigned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RemainderStrategy {
+    /// Learn the extra units on the first days
+    #[default]
+    FrontLoad,
+    /// Learn the extra units on the last days
+    BackLoad,
+    /// Spread the extra units evenly over the schedule
+    Spread,
+}
+
+/// Plans an even daily schedule to learn a range of units by a deadline.
+///
+/// Each learning day gets a [`UnitRange`] of the sequence. Skipped days have no limud, and when
+/// there are more learning days than units, the days without a unit have no limud either.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{BavliDafim, Daf, LimudCalendar, PacePlanner, SkipRules, Tractate};
+/// use icu_calendar::{cal::Hebrew, types::Weekday, Date};
+///
+/// let start = Date::try_new_iso(2024, 4, 30).unwrap().to_calendar(Hebrew);
+/// let deadline = Date::try_new_iso(2024, 6, 11).unwrap().to_calendar(Hebrew);
+/// let planner = PacePlanner::new(
+///     BavliDafim,
+///     Daf { tractate: Tractate::BavaMetzia, page: 2 },
+///     Daf { tractate: Tractate::BavaMetzia, page: 119 },
+///     start,
+///     deadline,
+/// )
+/// .unwrap()
+/// .skip_rules(SkipRules::new().skip_weekday(Weekday::Saturday));
+/// let first_day = start.limud(planner).unwrap();
+/// assert_eq!(first_day.start.page, 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PacePlanner<S: UnitSequence> {
+    sequence: S,
+    first_index: usize,
+    last_index: usize,
+    start_date: HebrewDate,
+    deadline: HebrewDate,
+    skip_rules: SkipRules,
+    remainder: RemainderStrategy,
+    learning_days: i32,
+}
+
+impl<S: UnitSequence> PacePlanner<S> {
+    /// Plan to learn from `start_unit` to `end_unit` (inclusive), from `start_date` until `deadline` (inclusive).
+    ///
+    /// # Returns
+    /// `None` if either unit is not part of the sequence, `end_unit` comes before `start_unit`
+    /// or `deadline` is before `start_date`
+    pub fn new(
+        sequence: S,
+        start_unit: S::Unit,
+        end_unit: S::Unit,
+        start_date: HebrewDate,
+        deadline: HebrewDate,
+    ) -> Option<Self> {
+        let first_index = sequence.position(&start_unit)?;
+        let last_index = sequence.position(&end_unit)?;
+        if last_index < first_index || deadline < start_date {
+            return None;
+        }
+        let mut planner = Self {
+            sequence,
+            first_index,
+            last_index,
+            start_date,
+            deadline,
+            skip_rules: SkipRules::new(),
+            remainder: RemainderStrategy::default(),
+            learning_days: 0,
+        };
+        planner.learning_days = planner.count_learning_days();
+        Some(planner)
+    }
+
+    /// Set the days on which there is no learning.
+    pub fn skip_rules(mut self, skip_rules: SkipRules) -> Self {
+        self.skip_rules = skip_rules;
+        self.learning_days = self.count_learning_days();
+        self
+    }
+
+    /// Set how units that do not divide evenly are assigned.
+    pub fn remainder(mut self, remainder: RemainderStrategy) -> Self {
+        self.remainder = remainder;
+        self
+    }
+
+    /// The number of units to learn.
+    pub fn unit_count(&self) -> usize {
+        self.last_index - self.first_index + 1
+    }
+
+    /// The number of days with learning between the start date and the deadline.
+    pub fn learning_days(&self) -> i32 {
+        self.learning_days
+    }
+
+    fn count_learning_days(&self) -> i32 {
+        let before_deadline = self.learning_days_before(self.deadline).unwrap_or(0);
+        if self.skip_rules.is_skipped(&self.deadline) {
+            before_deadline
+        } else {
+            before_deadline + 1
+        }
+    }
+
+    /// The number of learning days from the start date until (not including) the given date
+    fn learning_days_before(&self, date: HebrewDate) -> Option<i32> {
+        self.skip_rules.learning_days_between(self.start_date, date)
+    }
+
+    /// The offset of the first unit learned on the given learning day, relative to the first unit
+    fn day_start_offset(&self, day: i32) -> usize {
+        let units = self.unit_count();
+        let days = self.learning_days.max(1) as usize;
+        let day = day as usize;
+        let per_day = units / days;
+        let remainder = units % days;
+        match self.remainder {
+            RemainderStrategy::FrontLoad => day * per_day + day.min(remainder),
+            RemainderStrategy::BackLoad => day * per_day + day.saturating_sub(days - remainder),
+            RemainderStrategy::Spread => day * units / days,
+        }
+    }
+}
+
+impl<S: UnitSequence> InternalLimudCalculator<UnitRange<S::Unit>> for PacePlanner<S> {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(self.start_date)
+    }
+
+    fn find_interval(&self, limud_date: HebrewDate) -> Option<Interval> {
+        // Each interval is a day, so the learning days before it give its iteration directly
+        Some(Interval {
+            start_date: limud_date,
+            end_date: limud_date,
+            iteration: self.learning_days_before(limud_date)? + 1,
+            cycle: self.find_cycle(limud_date)?,
+        })
+    }
+
+    fn find_cycle(&self, date: HebrewDate) -> Option<Cycle> {
+        if date < self.start_date || date > self.deadline {
+            return None;
+        }
+        Some(Cycle {
+            start_date: self.start_date,
+            end_date: self.deadline,
+            iteration: Some(1),
+        })
+    }
+
+    fn is_skip_interval(&self, interval: &Interval) -> bool {
+        self.skip_rules.is_skipped(&interval.start_date)
+    }
+
+    fn skip_reason(&self, holiday: Option<NoLimudReason>) -> NoLimudReason {
+        self.skip_rules.skip_reason(holiday)
+    }
+
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<UnitRange<S::Unit>> {
+        // Skipped days do not advance the iteration, so it counts the learning days
+        let day = interval.iteration - 1;
+        let start = self.day_start_offset(day);
+        let end = self.day_start_offset(day + 1);
+        if end <= start {
+            return None;
+        }
+        Some(UnitRange {
+            start: self.sequence.get(self.first_index + start)?,
+            end: self.sequence.get(self.first_index + end - 1)?,
+        })
+    }
+}
+impl<S: UnitSequence> LimudCalculator<UnitRange<S::Unit>> for PacePlanner<S> {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use icu_calendar::types::Weekday;
+
+    use crate::{
+        date::{from_gregorian_date, DateExt},
+        sequence::{BavliDafim, Mishnayos, Psalms},
+        units::{Daf, Mishna, Tractate},
+    };
+
+    use super::*;
+
+    fn bava_metzia_planner() -> PacePlanner<BavliDafim> {
+        PacePlanner::new(
+            BavliDafim,
+            Daf {
+                tractate: Tractate::BavaMetzia,
+                page: 2,
+            },
+            Daf {
+                tractate: Tractate::BavaMetzia,
+                page: 119,
+            },
+            from_gregorian_date(2024, 4, 30),
+            from_gregorian_date(2024, 6, 11),
+        )
+        .expect("valid plan")
+    }
+
+    /// Every limud of the plan, checking that the ranges are contiguous
+    fn planned_pages(planner: &PacePlanner<BavliDafim>) -> (u16, u16, i32) {
+        let mut date = from_gregorian_date(2024, 4, 30);
+        let mut first = None;
+        let mut last = 0;
+        let mut days = 0;
+        while date <= from_gregorian_date(2024, 6, 11) {
+            if let Some(range) = planner.limud(date) {
+                if first.is_none() {
+                    first = Some(range.start.page);
+                } else {
+                    assert_eq!(range.start.page, last + 1);
+                }
+                last = range.end.page;
+                days += 1;
+            }
+            date = date.add_days(1).expect("date is valid");
+        }
+        (first.expect("plan has learning"), last, days)
+    }
+
+    #[test]
+    fn pace_planner_covers_every_unit() {
+        let planner = bava_metzia_planner();
+        assert_eq!(planner.unit_count(), 118);
+        assert_eq!(planner.learning_days(), 43);
+        assert_eq!(planned_pages(&planner), (2, 119, 43));
+    }
+
+    #[test]
+    fn pace_planner_remainder_strategies() {
+        // 118 dafim over 43 days: 32 days of 3 dafim and 11 days of 2 dafim
+        let front = bava_metzia_planner();
+        let first_day = front.limud(from_gregorian_date(2024, 4, 30)).expect("limud exists");
+        assert_eq!((first_day.start.page, first_day.end.page), (2, 4));
+        let back = bava_metzia_planner().remainder(RemainderStrategy::BackLoad);
+        let first_day = back.limud(from_gregorian_date(2024, 4, 30)).expect("limud exists");
+        assert_eq!((first_day.start.page, first_day.end.page), (2, 3));
+        let last_day = back.limud(from_gregorian_date(2024, 6, 11)).expect("limud exists");
+        assert_eq!((last_day.start.page, last_day.end.page), (117, 119));
+        let spread = bava_metzia_planner().remainder(RemainderStrategy::Spread);
+        assert_eq!(planned_pages(&spread), (2, 119, 43));
+    }
+
+    #[test]
+    fn pace_planner_skip_rules() {
+        let planner = bava_metzia_planner().skip_rules(SkipRules::new().skip_weekday(Weekday::Saturday));
+        // 2024-05-04 is Shabbos
+        assert!(planner.limud(from_gregorian_date(2024, 5, 4)).is_none());
+        assert_eq!(planner.learning_days(), 37);
+        assert_eq!(planned_pages(&planner), (2, 119, 37));
+    }
+
+    #[test]
+    fn pace_planner_outside_plan() {
+        let planner = bava_metzia_planner();
+        assert!(planner.limud(from_gregorian_date(2024, 4, 29)).is_none());
+        assert!(planner.limud(from_gregorian_date(2024, 6, 12)).is_none());
+    }
+
+    #[test]
+    fn pace_planner_more_days_than_units() {
+        let start = from_gregorian_date(2024, 1, 1);
+        let planner = PacePlanner::new(Psalms, 1, 3, start, from_gregorian_date(2024, 1, 5))
+            .expect("valid plan")
+            .remainder(RemainderStrategy::Spread);
+        let limudim: [Option<UnitRange<u8>>; 5] =
+            core::array::from_fn(|day| planner.limud(start.add_days(day as i32).expect("date is valid")));
+        assert_eq!(limudim.iter().flatten().count(), 3);
+        assert_eq!(limudim[4], Some(UnitRange { start: 3, end: 3 }));
+    }
+
+    #[test]
+    fn pace_planner_invalid_plans() {
+        let start = from_gregorian_date(2024, 1, 1);
+        let deadline = from_gregorian_date(2024, 12, 31);
+        assert!(PacePlanner::new(Psalms, 10, 1, start, deadline).is_none());
+        assert!(PacePlanner::new(Psalms, 1, 151, start, deadline).is_none());
+        assert!(PacePlanner::new(Psalms, 1, 150, deadline, start).is_none());
+        let mishna = Mishna {
+            tractate: Tractate::Berachos,
+            chapter: 1,
+            mishna: 1,
+        };
+        assert!(PacePlanner::new(Mishnayos, mishna, mishna, start, deadline).is_some());
+    }
+}
@@ -0,0 +1,525 @@
+use hebrew_holiday_calendar::HebrewMonth;
+
+use crate::{
+    cycle::Cycle,
+    date::{from_hebrew_date, DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    location::Location,
+    LimudCalculator,
+};
+
+/// A parsha (weekly portion) of the Torah.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum Parsha {
+    Bereishis,
+    Noach,
+    LechLecha,
+    Vayeira,
+    ChayeiSarah,
+    Toldos,
+    Vayeitzei,
+    Vayishlach,
+    Vayeishev,
+    Mikeitz,
+    Vayigash,
+    Vayechi,
+    Shemos,
+    Vaeira,
+    Bo,
+    Beshalach,
+    Yisro,
+    Mishpatim,
+    Terumah,
+    Tetzaveh,
+    KiSisa,
+    Vayakhel,
+    Pekudei,
+    Vayikra,
+    Tzav,
+    Shemini,
+    Tazria,
+    Metzora,
+    AchareiMos,
+    Kedoshim,
+    Emor,
+    Behar,
+    Bechukosai,
+    Bamidbar,
+    Naso,
+    Behaaloscha,
+    Shelach,
+    Korach,
+    Chukas,
+    Balak,
+    Pinchas,
+    Matos,
+    Masei,
+    Devarim,
+    Vaeschanan,
+    Eikev,
+    Reeh,
+    Shoftim,
+    KiSeitzei,
+    KiSavo,
+    Nitzavim,
+    Vayeilech,
+    Haazinu,
+    VezosHaberacha,
+}
+
+/// Every parsha of the Torah, in order.
+pub const ALL_PARSHIYOS: [Parsha; 54] = [
+    Parsha::Bereishis,
+    Parsha::Noach,
+    Parsha::LechLecha,
+    Parsha::Vayeira,
+    Parsha::ChayeiSarah,
+    Parsha::Toldos,
+    Parsha::Vayeitzei,
+    Parsha::Vayishlach,
+    Parsha::Vayeishev,
+    Parsha::Mikeitz,
+    Parsha::Vayigash,
+    Parsha::Vayechi,
+    Parsha::Shemos,
+    Parsha::Vaeira,
+    Parsha::Bo,
+    Parsha::Beshalach,
+    Parsha::Yisro,
+    Parsha::Mishpatim,
+    Parsha::Terumah,
+    Parsha::Tetzaveh,
+    Parsha::KiSisa,
+    Parsha::Vayakhel,
+    Parsha::Pekudei,
+    Parsha::Vayikra,
+    Parsha::Tzav,
+    Parsha::Shemini,
+    Parsha::Tazria,
+    Parsha::Metzora,
+    Parsha::AchareiMos,
+    Parsha::Kedoshim,
+    Parsha::Emor,
+    Parsha::Behar,
+    Parsha::Bechukosai,
+    Parsha::Bamidbar,
+    Parsha::Naso,
+    Parsha::Behaaloscha,
+    Parsha::Shelach,
+    Parsha::Korach,
+    Parsha::Chukas,
+    Parsha::Balak,
+    Parsha::Pinchas,
+    Parsha::Matos,
+    Parsha::Masei,
+    Parsha::Devarim,
+    Parsha::Vaeschanan,
+    Parsha::Eikev,
+    Parsha::Reeh,
+    Parsha::Shoftim,
+    Parsha::KiSeitzei,
+    Parsha::KiSavo,
+    Parsha::Nitzavim,
+    Parsha::Vayeilech,
+    Parsha::Haazinu,
+    Parsha::VezosHaberacha,
+];
+
+/// The parsha read on a Shabbos
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParshaReading {
+    /// A single parsha
+    Single(Parsha),
+    /// Two consecutive parshiyos read together
+    Combined(Parsha, Parsha),
+}
+
+/// The pairs combined after Pesach when a regular year is short of Shabbosos, by their first
+/// parsha, in the order they are combined
+const REGULAR_YEAR_PAIRS: [Parsha; 5] = [
+    Parsha::Tazria,
+    Parsha::AchareiMos,
+    Parsha::Behar,
+    Parsha::Matos,
+    Parsha::Chukas,
+];
+
+/// The pairs combined when a leap year is short of Shabbosos, in the order they are combined
+const LEAP_YEAR_PAIRS: [Parsha; 2] = [Parsha::Matos, Parsha::Chukas];
+
+/// The set of parshiyos combined in a year, by their first parsha
+#[derive(Clone, Copy, Default)]
+struct Combinations(u64);
+
+impl Combinations {
+    fn combine(&mut self, first: Parsha) {
+        self.0 |= 1 << first as u64;
+    }
+    fn separate(&mut self, first: Parsha) {
+        self.0 &= !(1 << first as u64);
+    }
+    fn is_combined(self, first: Parsha) -> bool {
+        self.0 & (1 << first as u64) != 0
+    }
+}
+
+/// The Shabbos on or before the given date
+fn shabbos_on_or_before(date: HebrewDate) -> Option<HebrewDate> {
+    date.add_days(-(date.day_of_week_number() % 7))
+}
+
+/// Whether a Shabbos is a Yom Tov or Chol Hamoed, when the holiday reading replaces the parsha
+fn is_holiday_shabbos(shabbos: &HebrewDate, location: Location) -> bool {
+    shabbos.is_yom_tov(location) || shabbos.is_chol_hamoed(location)
+}
+
+/// The number of Shabbosos with a parsha from `first` to `last`, both Shabbosos, inclusive
+fn parsha_shabbos_count(first: HebrewDate, last: HebrewDate, location: Location) -> Option<i32> {
+    let mut count = 0;
+    let mut shabbos = first;
+    while shabbos <= last {
+        if !is_holiday_shabbos(&shabbos, location) {
+            count += 1;
+        }
+        shabbos = shabbos.add_days(7)?;
+    }
+    Some(count)
+}
+
+/// Combine the first `count` pairs, failing if there are not enough pairs to make up the difference
+fn combine_first(combinations: &mut Combinations, pairs: &[Parsha], count: i32) -> Option<()> {
+    let count = usize::try_from(count).ok()?;
+    for first in pairs.get(..count)? {
+        combinations.combine(*first);
+    }
+    Some(())
+}
+
+/// The diaspora's combinations for the year that begins with the given Shabbos Bereishis.
+///
+/// The year is divided at the Shabbos before Pesach, when Tzav is read in a regular year, the
+/// Shabbos before Tisha B'Av, when Devarim is read, and the last Shabbos of the year, when
+/// Nitzavim is read. Each part combines as many pairs as it is short of Shabbosos.
+fn diaspora_combinations(year: i32, bereishis: HebrewDate, last: HebrewDate) -> Option<Combinations> {
+    let location = Location::Diaspora;
+    let devarim = shabbos_on_or_before(from_hebrew_date(year, HebrewMonth::Av, 9))?;
+    let mut combinations = Combinations::default();
+
+    if from_hebrew_date(year, HebrewMonth::Tishrei, 1).is_in_leap_year() {
+        let shabbosos = parsha_shabbos_count(bereishis, devarim, location)?;
+        combine_first(
+            &mut combinations,
+            &LEAP_YEAR_PAIRS,
+            Parsha::Devarim as i32 + 1 - shabbosos,
+        )?;
+    } else {
+        let tzav = shabbos_on_or_before(from_hebrew_date(year, HebrewMonth::Nissan, 14))?;
+        let before_pesach = parsha_shabbos_count(bereishis, tzav, location)?;
+        combine_first(
+            &mut combinations,
+            &[Parsha::Vayakhel],
+            Parsha::Tzav as i32 + 1 - before_pesach,
+        )?;
+        let after_pesach = parsha_shabbos_count(tzav.add_days(7)?, devarim, location)?;
+        combine_first(
+            &mut combinations,
+            &REGULAR_YEAR_PAIRS,
+            Parsha::Devarim as i32 - Parsha::Tzav as i32 - after_pesach,
+        )?;
+    }
+
+    let after_tisha_bav = parsha_shabbos_count(devarim.add_days(7)?, last, location)?;
+    let last_parsha = if reads_vayeilech_in_tishrei(year + 1) {
+        Parsha::Nitzavim
+    } else {
+        Parsha::Vayeilech
+    };
+    combine_first(
+        &mut combinations,
+        &[Parsha::Nitzavim],
+        last_parsha as i32 - Parsha::Devarim as i32 - after_tisha_bav,
+    )?;
+    Some(combinations)
+}
+
+/// Whether Vayeilech is read on its own between Rosh Hashanah and Sukkos of the given year.
+///
+/// This happens when Rosh Hashanah falls on Monday or Tuesday, leaving two Shabbosos for
+/// Vayeilech and Haazinu.
+fn reads_vayeilech_in_tishrei(year: i32) -> bool {
+    matches!(
+        from_hebrew_date(year, HebrewMonth::Tishrei, 1).day_of_week_number(),
+        2 | 3
+    )
+}
+
+/// The reading for a Shabbos in Tishrei, before Shabbos Bereishis
+fn tishrei_reading(shabbos: &HebrewDate) -> Option<ParshaReading> {
+    let year = shabbos.year().extended_year();
+    match shabbos.day_of_month().0 {
+        3..=9 if reads_vayeilech_in_tishrei(year) => Some(ParshaReading::Single(Parsha::Vayeilech)),
+        3..=9 | 11..=14 => Some(ParshaReading::Single(Parsha::Haazinu)),
+        // Rosh Hashanah, Yom Kippur, Sukkos and Shemini Atzeres
+        _ => None,
+    }
+}
+
+/// The parsha read on the given Shabbos, or `None` if a holiday reading replaces it.
+pub(crate) fn parsha_for_shabbos(shabbos: HebrewDate, location: Location) -> Option<ParshaReading> {
+    let year = shabbos.year().extended_year();
+    let bereishis = shabbos_on_or_before(from_hebrew_date(year, HebrewMonth::Tishrei, 29))?;
+    if shabbos < bereishis {
+        return tishrei_reading(&shabbos);
+    }
+    if is_holiday_shabbos(&shabbos, location) {
+        return None;
+    }
+    let last = shabbos_on_or_before(from_hebrew_date(year + 1, HebrewMonth::Tishrei, 1).add_days(-1)?)?;
+    let mut combinations = diaspora_combinations(year, bereishis, last)?;
+    if location.in_israel() {
+        // In Israel, the last day of Pesach or the second day of Shavuos on Shabbos leaves an
+        // extra Shabbos, so Israel reads a pair separately that the diaspora combines
+        let pesach = from_hebrew_date(year, HebrewMonth::Nissan, 22);
+        let shavuos = from_hebrew_date(year, HebrewMonth::Sivan, 7);
+        if pesach.day_of_week_number() == 7 {
+            if combinations.is_combined(Parsha::Behar) {
+                combinations.separate(Parsha::Behar);
+            } else {
+                combinations.separate(Parsha::Matos);
+            }
+        } else if shavuos.day_of_week_number() == 7 {
+            combinations.separate(Parsha::Chukas);
+        }
+    }
+
+    let mut index = 0;
+    let mut current = bereishis;
+    loop {
+        let parsha = *ALL_PARSHIYOS.get(index)?;
+        let combined = combinations.is_combined(parsha);
+        if current == shabbos {
+            return if combined {
+                Some(ParshaReading::Combined(parsha, *ALL_PARSHIYOS.get(index + 1)?))
+            } else {
+                Some(ParshaReading::Single(parsha))
+            };
+        }
+        if !is_holiday_shabbos(&current, location) {
+            index += if combined { 2 } else { 1 };
+        }
+        current = current.add_days(7)?;
+    }
+}
+
+/// Calculates the parsha of the week (parashas hashavua).
+///
+/// The limud for any day is the parsha read on the coming Shabbos. Weeks whose Shabbos is a
+/// Yom Tov or Chol Hamoed have no parsha. After the last day of Pesach or the second day of
+/// Shavuos falls on Shabbos, Israel is a parsha ahead of the diaspora until the diaspora
+/// combines a pair that Israel reads separately.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{LimudCalendar, Location, Parsha, ParshaHashavua, ParshaReading};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// // 2023-05-27 is the second day of Shavuos outside Israel
+/// let date = Date::try_new_iso(2023, 5, 27).unwrap().to_calendar(Hebrew);
+/// let israel = date.limud(ParshaHashavua::new(Location::Israel));
+/// assert_eq!(israel, Some(ParshaReading::Single(Parsha::Naso)));
+/// assert!(date.limud(ParshaHashavua::new(Location::Diaspora)).is_none());
+/// ```
+#[derive(Default)]
+pub struct ParshaHashavua {
+    /// Whether the calculator is for Israel or the diaspora
+    pub location: Location,
+}
+
+impl ParshaHashavua {
+    /// Create a new parsha calculator.
+    ///
+    /// # Arguments
+    /// * `location` - Whether the calculator is for Israel or the diaspora
+    ///
+    /// # Returns
+    /// A new parsha calculator.
+    pub const fn new(location: Location) -> Self {
+        Self { location }
+    }
+
+    /// Each cycle is the weeks whose Shabbos falls in one Hebrew year, from the Sunday before
+    /// Rosh Hashanah until the last Shabbos of the year.
+    pub(crate) fn find_yearly_cycle(date: HebrewDate) -> (HebrewDate, HebrewDate) {
+        let year = date.year().extended_year();
+        let week_start = |year: i32| {
+            let rosh_hashana = from_hebrew_date(year, HebrewMonth::Tishrei, 1);
+            rosh_hashana
+                .add_days(1 - rosh_hashana.day_of_week_number())
+                .unwrap_or(rosh_hashana)
+        };
+        let (start_date, next_start) = if date >= week_start(year + 1) {
+            (week_start(year + 1), week_start(year + 2))
+        } else {
+            (week_start(year), week_start(year + 1))
+        };
+        (start_date, next_start.add_days(-1).unwrap_or(next_start))
+    }
+}
+
+impl InternalLimudCalculator<ParshaReading> for ParshaHashavua {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Perpetual(Self::find_yearly_cycle)
+    }
+
+    fn interval_end_calculation(_cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
+        // Each interval is a week, ending on Shabbos
+        hebrew_date.add_days(7 - hebrew_date.day_of_week_number())
+    }
+
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<ParshaReading> {
+        parsha_for_shabbos(interval.end_date, self.location)
+    }
+}
+impl LimudCalculator<ParshaReading> for ParshaHashavua {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::date::from_gregorian_date;
+
+    use super::*;
+
+    fn parsha(year: i32, month: u8, day: u8, location: Location) -> Option<ParshaReading> {
+        ParshaHashavua::new(location).limud(from_gregorian_date(year, month, day))
+    }
+
+    #[test]
+    fn parsha_bereishis() {
+        // Simchas Torah 5785 was Friday 2024-10-25
+        assert_eq!(
+            parsha(2024, 10, 26, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Bereishis))
+        );
+        assert_eq!(
+            parsha(2024, 10, 21, Location::Israel),
+            Some(ParshaReading::Single(Parsha::Bereishis))
+        );
+    }
+
+    #[test]
+    fn parsha_tishrei() {
+        // Rosh Hashanah 5785 was on Thursday, so Vayeilech was read with Nitzavim
+        assert_eq!(
+            parsha(2024, 9, 28, Location::Diaspora),
+            Some(ParshaReading::Combined(Parsha::Nitzavim, Parsha::Vayeilech))
+        );
+        assert_eq!(
+            parsha(2024, 10, 5, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Haazinu))
+        );
+        // Yom Kippur and Sukkos
+        assert!(parsha(2024, 10, 12, Location::Diaspora).is_none());
+        assert!(parsha(2024, 10, 19, Location::Israel).is_none());
+        // Rosh Hashanah 5786 was on Tuesday
+        assert_eq!(
+            parsha(2025, 9, 20, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Nitzavim))
+        );
+        assert_eq!(
+            parsha(2025, 9, 27, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Vayeilech))
+        );
+        assert_eq!(
+            parsha(2025, 10, 4, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Haazinu))
+        );
+    }
+
+    #[test]
+    fn parsha_combined_before_pesach() {
+        assert_eq!(
+            parsha(2026, 3, 14, Location::Diaspora),
+            Some(ParshaReading::Combined(Parsha::Vayakhel, Parsha::Pekudei))
+        );
+        // 5785 was long enough to read Vayakhel and Pekudei separately
+        assert_eq!(
+            parsha(2025, 3, 22, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Vayakhel))
+        );
+        assert_eq!(
+            parsha(2025, 3, 29, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Pekudei))
+        );
+        assert_eq!(
+            parsha(2025, 4, 12, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Tzav))
+        );
+        assert!(parsha(2025, 4, 19, Location::Israel).is_none());
+    }
+
+    #[test]
+    fn parsha_israel_ahead_after_shavuos() {
+        // The second day of Shavuos 5783 was on Shabbos
+        assert!(parsha(2023, 5, 27, Location::Diaspora).is_none());
+        assert_eq!(
+            parsha(2023, 6, 3, Location::Diaspora),
+            Some(ParshaReading::Single(Parsha::Naso))
+        );
+        assert_eq!(
+            parsha(2023, 6, 3, Location::Israel),
+            Some(ParshaReading::Single(Parsha::Behaaloscha))
+        );
+        assert_eq!(
+            parsha(2023, 7, 1, Location::Diaspora),
+            Some(ParshaReading::Combined(Parsha::Chukas, Parsha::Balak))
+        );
+        assert_eq!(
+            parsha(2023, 7, 1, Location::Israel),
+            Some(ParshaReading::Single(Parsha::Balak))
+        );
+        assert_eq!(
+            parsha(2023, 7, 8, Location::Israel),
+            parsha(2023, 7, 8, Location::Diaspora)
+        );
+    }
+
+    #[test]
+    fn parsha_israel_ahead_after_pesach() {
+        // The last day of Pesach 5782 (a leap year) was on Shabbos
+        assert!(parsha(2022, 4, 23, Location::Diaspora).is_none());
+        assert_eq!(
+            parsha(2022, 4, 23, Location::Israel),
+            Some(ParshaReading::Single(Parsha::AchareiMos))
+        );
+        assert_eq!(
+            parsha(2022, 7, 30, Location::Diaspora),
+            Some(ParshaReading::Combined(Parsha::Matos, Parsha::Masei))
+        );
+        assert_eq!(
+            parsha(2022, 7, 30, Location::Israel),
+            Some(ParshaReading::Single(Parsha::Masei))
+        );
+    }
+
+    #[test]
+    fn parsha_every_year_fits() {
+        // Every Shabbos has a reading or a holiday, and every year ends with Nitzavim
+        for year in 5700..5900 {
+            for location in [Location::Israel, Location::Diaspora] {
+                let rosh_hashana = from_hebrew_date(year + 1, HebrewMonth::Tishrei, 1);
+                let last =
+                    shabbos_on_or_before(rosh_hashana.add_days(-1).expect("date is valid")).expect("date is valid");
+                let reading = parsha_for_shabbos(last, location).expect("reading exists");
+                if reads_vayeilech_in_tishrei(year + 1) {
+                    assert_eq!(reading, ParshaReading::Single(Parsha::Nitzavim));
+                } else {
+                    assert_eq!(reading, ParshaReading::Combined(Parsha::Nitzavim, Parsha::Vayeilech));
+                }
+            }
+        }
+    }
+}
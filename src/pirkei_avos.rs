@@ -1,10 +1,12 @@
 use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth};
 
 use crate::{
+    annotations::{Annotation, Annotations},
     cycle::Cycle,
     date::{from_hebrew_date, DateExt, HebrewDate},
     interval::Interval,
     limud_calculator::{CycleFinder, InternalLimudCalculator},
+    location::Location,
     LimudCalculator,
 };
 
@@ -94,6 +96,12 @@ impl InternalLimudCalculator<PirkeiAvosUnit> for PirkeiAvos {
 
         false
     }
+
+    fn annotate(&self, unit: &PirkeiAvosUnit, _interval: &Interval, annotations: &mut Annotations) {
+        if let PirkeiAvosUnit::Combined(first, second) = *unit {
+            annotations.push(Annotation::CombinedPirkeiAvos(first, second));
+        }
+    }
 }
 impl LimudCalculator<PirkeiAvosUnit> for PirkeiAvos {}
 
@@ -109,6 +117,19 @@ impl PirkeiAvos {
         Self { in_israel }
     }
 
+    /// Create a new Pirkei Avos calculator for a location.
+    ///
+    /// # Arguments
+    /// * `location` - Whether the calculator is for Israel or the diaspora
+    ///
+    /// # Returns
+    /// A new Pirkei Avos calculator.
+    pub const fn with_location(location: Location) -> Self {
+        Self {
+            in_israel: location.in_israel(),
+        }
+    }
+
     fn find_yearly_cycle_israel(date: HebrewDate) -> (HebrewDate, HebrewDate) {
         Self::find_yearly_cycle(true, date)
     }
@@ -304,4 +325,10 @@ mod tests {
         // Python test expects description '2'
         assert_eq!(limud, PirkeiAvosUnit::Single(2));
     }
+
+    #[test]
+    fn test_with_location() {
+        assert!(PirkeiAvos::with_location(Location::Israel).in_israel);
+        assert!(!PirkeiAvos::with_location(Location::Diaspora).in_israel);
+    }
 }
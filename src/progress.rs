@@ -0,0 +1,258 @@
+use crate::{
+    cycle::{Cycle, CycleInfo},
+    date::{DateExt, HebrewDate},
+    limud_calculator::InternalLimudCalculator,
+    units::Tractate,
+};
+
+/// How far into its cycle a schedule is on a given date.
+///
+/// Units are the schedule's own learning units (dafim, amudim or mishnayos), so the
+/// percentages are weighted by the amount of learning rather than by days.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{DafYomiBavli, LimudCalculator};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let date = Date::try_new_iso(2020, 1, 4).unwrap().to_calendar(Hebrew);
+/// let progress = DafYomiBavli::default().progress(date).unwrap();
+/// assert_eq!(progress.units_remaining, 0);
+/// assert_eq!(progress.days_to_next_tractate, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleProgress {
+    /// The cycle the date falls in
+    pub cycle: CycleInfo,
+    /// The number of units learned in this cycle, up to and including the date
+    pub units_completed: i32,
+    /// The number of units still to be learned in this cycle
+    pub units_remaining: i32,
+    /// The number of days after the date until the end of the cycle
+    pub days_remaining: i32,
+    /// The tractate currently being learned
+    pub tractate: Tractate,
+    /// The number of units of the current tractate learned, up to and including the date
+    pub tractate_units_completed: i32,
+    /// The number of units in the current tractate
+    pub tractate_unit_count: i32,
+    /// The date the next tractate begins (the start of the next cycle after the last tractate)
+    pub next_tractate_date: HebrewDate,
+    /// The number of days from the date until the next tractate begins
+    pub days_to_next_tractate: i32,
+    /// The date the next seder begins (the start of the next cycle after the last seder)
+    pub next_seder_date: HebrewDate,
+    /// The number of days from the date until the next seder begins
+    pub days_to_next_seder: i32,
+}
+
+impl CycleProgress {
+    /// The percentage of the cycle's units that have been learned.
+    pub fn percent_complete(&self) -> f64 {
+        percent(self.units_completed, self.cycle.unit_count)
+    }
+
+    /// The percentage of the current tractate's units that have been learned.
+    pub fn tractate_percent_complete(&self) -> f64 {
+        percent(self.tractate_units_completed, self.tractate_unit_count)
+    }
+}
+
+fn percent(completed: i32, total: i32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        completed as f64 * 100.0 / total as f64
+    }
+}
+
+pub(crate) fn cycle_progress<T, C: InternalLimudCalculator<T> + ?Sized>(
+    calculator: &C,
+    date: HebrewDate,
+) -> Option<CycleProgress> {
+    let interval = calculator.find_interval(date)?;
+    let cycle = interval.cycle;
+    let number = cycle.iteration?;
+    let unit_count = C::cycle_unit_count(number)?;
+    let units_completed = calculator.units_completed(&interval, &date)?.clamp(0, unit_count);
+
+    // The current tractate is the one holding the last unit learned, or the first tractate
+    // if nothing has been learned yet (e.g. a skip day at the start of a cycle)
+    let current_index = (units_completed - 1).max(0);
+    let tractates = calculator.tractates();
+    let mut tractate_start = 0;
+    let mut position = None;
+    for (index, tractate) in tractates.iter().enumerate() {
+        let count = C::tractate_unit_count(*tractate, number);
+        if current_index < tractate_start + count {
+            position = Some((index, *tractate));
+            break;
+        }
+        tractate_start += count;
+    }
+    let (position, tractate) = position?;
+    let tractate_unit_count = C::tractate_unit_count(tractate, number);
+
+    let next_tractate_index = tractate_start + tractate_unit_count;
+    let next_seder_index = next_tractate_index
+        + tractates[position + 1..]
+            .iter()
+            .take_while(|next| next.seder() == tractate.seder())
+            .map(|next| C::tractate_unit_count(*next, number))
+            .sum::<i32>();
+    let next_tractate_date = unit_date(calculator, &cycle, next_tractate_index, unit_count)?;
+    let next_seder_date = unit_date(calculator, &cycle, next_seder_index, unit_count)?;
+
+    Some(CycleProgress {
+        cycle: CycleInfo {
+            number,
+            start_date: cycle.start_date,
+            end_date: cycle.end_date,
+            unit_count,
+        },
+        units_completed,
+        units_remaining: unit_count - units_completed,
+        days_remaining: date.days_until(&cycle.end_date)?,
+        tractate,
+        tractate_units_completed: (units_completed - tractate_start).max(0),
+        tractate_unit_count,
+        next_tractate_date,
+        days_to_next_tractate: date.days_until(&next_tractate_date)?,
+        next_seder_date,
+        days_to_next_seder: date.days_until(&next_seder_date)?,
+    })
+}
+
+/// The date the unit at the given index is learned, continuing into the next cycle past the last unit
+fn unit_date<T, C: InternalLimudCalculator<T> + ?Sized>(
+    calculator: &C,
+    cycle: &Cycle,
+    index: i32,
+    unit_count: i32,
+) -> Option<HebrewDate> {
+    if index >= unit_count {
+        cycle.end_date.add_days(1)
+    } else {
+        calculator.date_for_unit(cycle, index)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+
+    use crate::{
+        date::{from_gregorian_date, from_hebrew_date},
+        AmudYomiBavliDirshu, DafHashavuaBavli, DafYomiBavli, DafYomiYerushalmiVilna, LimudCalculator, MishnaYomis,
+        PirkeiAvos, TehillimMonthly,
+    };
+
+    use super::*;
+
+    #[test]
+    fn progress_daf_yomi_bavli_first_day() {
+        let test_date = from_gregorian_date(2020, 1, 5);
+        let progress = DafYomiBavli::default().progress(test_date).expect("progress exists");
+        assert_eq!(progress.cycle.number, 14);
+        assert_eq!(progress.units_completed, 1);
+        assert_eq!(progress.units_remaining, 2710);
+        assert_eq!(progress.days_remaining, 2710);
+        assert_eq!(progress.tractate, Tractate::Berachos);
+        assert_eq!(progress.tractate_units_completed, 1);
+        assert_eq!(progress.tractate_unit_count, 63);
+        // Berachos is the only tractate of Zeraim in the Bavli, so Shabbos also begins Moed
+        assert_eq!(progress.days_to_next_tractate, 63);
+        assert_eq!(progress.next_tractate_date, progress.next_seder_date);
+    }
+
+    #[test]
+    fn progress_daf_yomi_bavli_seder_boundary() {
+        // Shevuos 30 in the 13th cycle
+        let test_date = from_gregorian_date(2017, 12, 28);
+        let progress = DafYomiBavli::default().progress(test_date).expect("progress exists");
+        assert_eq!(progress.tractate, Tractate::Shevuos);
+        assert_eq!(progress.tractate_units_completed, 29);
+        assert_eq!(progress.days_to_next_tractate, 20);
+        // Avodah Zarah (75 dafim) and Horiyos (13 dafim) remain in Nezikin
+        assert_eq!(progress.days_to_next_seder, 20 + 75 + 13);
+        let next = DafYomiBavli::default()
+            .limud(progress.next_seder_date)
+            .expect("limud exists");
+        assert_eq!(next.tractate, Tractate::Zevachim);
+        assert_eq!(next.page, 2);
+    }
+
+    #[test]
+    fn progress_percent_complete() {
+        let test_date = from_gregorian_date(2020, 1, 4);
+        let progress = DafYomiBavli::default().progress(test_date).expect("progress exists");
+        assert_eq!(progress.units_remaining, 0);
+        assert_eq!(progress.days_remaining, 0);
+        assert_eq!(progress.percent_complete(), 100.0);
+        assert_eq!(progress.tractate_percent_complete(), 100.0);
+        assert_eq!(progress.next_tractate_date, from_gregorian_date(2020, 1, 5));
+    }
+
+    #[test]
+    fn progress_yerushalmi_skip_day() {
+        let yom_kippur = from_hebrew_date(5778, HebrewMonth::Tishrei, 10);
+        let calculator = DafYomiYerushalmiVilna::default();
+        let before = calculator
+            .progress(yom_kippur.add_days(-1).expect("date is valid"))
+            .expect("progress exists");
+        let on = calculator.progress(yom_kippur).expect("progress exists");
+        let after = calculator
+            .progress(yom_kippur.add_days(1).expect("date is valid"))
+            .expect("progress exists");
+        assert_eq!(on.units_completed, before.units_completed);
+        assert_eq!(after.units_completed, before.units_completed + 1);
+        assert_eq!(on.days_remaining, after.days_remaining + 1);
+        // The skip day pushes the next tractate back by a day
+        assert_eq!(on.next_tractate_date, after.next_tractate_date);
+        assert_eq!(calculator.limud(on.next_tractate_date).map(|daf| daf.page), Some(1));
+    }
+
+    #[test]
+    fn progress_daf_hashavua_weekly() {
+        let calculator = DafHashavuaBavli::default();
+        let sunday = from_gregorian_date(2005, 3, 6);
+        let shabbos = from_gregorian_date(2005, 3, 12);
+        let first = calculator.progress(sunday).expect("progress exists");
+        let last = calculator.progress(shabbos).expect("progress exists");
+        assert_eq!(first.units_completed, 1);
+        assert_eq!(last.units_completed, 1);
+        assert_eq!(first.days_to_next_tractate, 63 * 7);
+        assert_eq!(last.days_to_next_tractate, 63 * 7 - 6);
+    }
+
+    #[test]
+    fn progress_mishna_yomis() {
+        let test_date = from_gregorian_date(2016, 4, 27);
+        let progress = MishnaYomis.progress(test_date).expect("progress exists");
+        assert_eq!(progress.units_completed, 58);
+        assert_eq!(progress.tractate, Tractate::Peah);
+        assert_eq!(progress.tractate_units_completed, 1);
+        assert_eq!(progress.units_remaining, 4192 - 58);
+    }
+
+    #[test]
+    fn progress_amud_yomi() {
+        let test_date = from_gregorian_date(2023, 10, 16);
+        let progress = AmudYomiBavliDirshu::default()
+            .progress(test_date)
+            .expect("progress exists");
+        assert_eq!(progress.tractate_unit_count, 125);
+        assert_eq!(progress.days_to_next_tractate, 125);
+    }
+
+    #[test]
+    fn progress_unsupported() {
+        let test_date = from_gregorian_date(2020, 1, 4);
+        assert!(TehillimMonthly.progress(test_date).is_none());
+        assert!(PirkeiAvos::default().progress(test_date).is_none());
+        assert!(DafYomiBavli::default()
+            .progress(from_gregorian_date(1920, 1, 1))
+            .is_none());
+    }
+}
@@ -0,0 +1,566 @@
+use crate::{
+    constants::{RAMBAM_INTRODUCTION_UNITS, RAMBAM_UNIT_COUNT},
+    cycle::Cycle,
+    date::{from_gregorian_date, DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    LimudCalculator,
+};
+
+/// One of the fourteen books (sefarim) of the Mishneh Torah.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum Sefer {
+    Madda,
+    Ahavah,
+    Zmanim,
+    Nashim,
+    Kedushah,
+    Haflaah,
+    Zeraim,
+    Avodah,
+    Korbanos,
+    Taharah,
+    Nezikin,
+    Kinyan,
+    Mishpatim,
+    Shoftim,
+}
+
+/// One of the sections (hilchos) of the Mishneh Torah.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum Halachos {
+    YesodeiHaTorah,
+    Deos,
+    TalmudTorah,
+    AvodahZarah,
+    Teshuvah,
+    KriasShema,
+    Tefillah,
+    Tefillin,
+    Tzitzis,
+    Berachos,
+    Milah,
+    Shabbos,
+    Eruvin,
+    ShevisasAsor,
+    ShevisasYomTov,
+    ChametzUMatzah,
+    ShofarSukkahVeLulav,
+    Shekalim,
+    KiddushHaChodesh,
+    Taaniyos,
+    MegillahVeChanukah,
+    Ishus,
+    Gerushin,
+    YibumVeChalitzah,
+    NaarahBesulah,
+    Sotah,
+    IssureiBiah,
+    MaachalosAsuros,
+    Shechitah,
+    Shevuos,
+    Nedarim,
+    Nezirus,
+    ArachinVaCharamin,
+    Kilayim,
+    MatnosAniyim,
+    Terumos,
+    Maaser,
+    MaaserSheni,
+    Bikkurim,
+    ShemitahVeYovel,
+    BeisHaBechirah,
+    KleiHaMikdash,
+    BiasHaMikdash,
+    IssureiMizbeach,
+    MaasehHaKorbanos,
+    TemidinUMusafin,
+    PesuleiHaMukdashin,
+    AvodasYomHaKippurim,
+    Meilah,
+    KorbanPesach,
+    Chagigah,
+    Bechoros,
+    Shegagos,
+    MechusreiKapparah,
+    Temurah,
+    TumasMeis,
+    ParahAdumah,
+    TumasTzaraas,
+    MetameiMishkavUMoshav,
+    ShearAvosHaTumos,
+    TumasOchalin,
+    Kelim,
+    Mikvaos,
+    NizkeiMamon,
+    Geneivah,
+    GezeilahVaAveidah,
+    ChovelUMazik,
+    RotzeachUShmirasNefesh,
+    Mechirah,
+    ZechiyahUMattanah,
+    Shecheinim,
+    SheluchinVeShutafin,
+    Avadim,
+    Sechirus,
+    SheilahUPikadon,
+    MalvehVeLoveh,
+    ToenVeNitan,
+    Nachalos,
+    Sanhedrin,
+    Edus,
+    Mamrim,
+    Evel,
+    Melachim,
+}
+
+/// Every section of the Mishneh Torah, in order.
+pub const ALL_HALACHOS: [Halachos; 83] = [
+    Halachos::YesodeiHaTorah,
+    Halachos::Deos,
+    Halachos::TalmudTorah,
+    Halachos::AvodahZarah,
+    Halachos::Teshuvah,
+    Halachos::KriasShema,
+    Halachos::Tefillah,
+    Halachos::Tefillin,
+    Halachos::Tzitzis,
+    Halachos::Berachos,
+    Halachos::Milah,
+    Halachos::Shabbos,
+    Halachos::Eruvin,
+    Halachos::ShevisasAsor,
+    Halachos::ShevisasYomTov,
+    Halachos::ChametzUMatzah,
+    Halachos::ShofarSukkahVeLulav,
+    Halachos::Shekalim,
+    Halachos::KiddushHaChodesh,
+    Halachos::Taaniyos,
+    Halachos::MegillahVeChanukah,
+    Halachos::Ishus,
+    Halachos::Gerushin,
+    Halachos::YibumVeChalitzah,
+    Halachos::NaarahBesulah,
+    Halachos::Sotah,
+    Halachos::IssureiBiah,
+    Halachos::MaachalosAsuros,
+    Halachos::Shechitah,
+    Halachos::Shevuos,
+    Halachos::Nedarim,
+    Halachos::Nezirus,
+    Halachos::ArachinVaCharamin,
+    Halachos::Kilayim,
+    Halachos::MatnosAniyim,
+    Halachos::Terumos,
+    Halachos::Maaser,
+    Halachos::MaaserSheni,
+    Halachos::Bikkurim,
+    Halachos::ShemitahVeYovel,
+    Halachos::BeisHaBechirah,
+    Halachos::KleiHaMikdash,
+    Halachos::BiasHaMikdash,
+    Halachos::IssureiMizbeach,
+    Halachos::MaasehHaKorbanos,
+    Halachos::TemidinUMusafin,
+    Halachos::PesuleiHaMukdashin,
+    Halachos::AvodasYomHaKippurim,
+    Halachos::Meilah,
+    Halachos::KorbanPesach,
+    Halachos::Chagigah,
+    Halachos::Bechoros,
+    Halachos::Shegagos,
+    Halachos::MechusreiKapparah,
+    Halachos::Temurah,
+    Halachos::TumasMeis,
+    Halachos::ParahAdumah,
+    Halachos::TumasTzaraas,
+    Halachos::MetameiMishkavUMoshav,
+    Halachos::ShearAvosHaTumos,
+    Halachos::TumasOchalin,
+    Halachos::Kelim,
+    Halachos::Mikvaos,
+    Halachos::NizkeiMamon,
+    Halachos::Geneivah,
+    Halachos::GezeilahVaAveidah,
+    Halachos::ChovelUMazik,
+    Halachos::RotzeachUShmirasNefesh,
+    Halachos::Mechirah,
+    Halachos::ZechiyahUMattanah,
+    Halachos::Shecheinim,
+    Halachos::SheluchinVeShutafin,
+    Halachos::Avadim,
+    Halachos::Sechirus,
+    Halachos::SheilahUPikadon,
+    Halachos::MalvehVeLoveh,
+    Halachos::ToenVeNitan,
+    Halachos::Nachalos,
+    Halachos::Sanhedrin,
+    Halachos::Edus,
+    Halachos::Mamrim,
+    Halachos::Evel,
+    Halachos::Melachim,
+];
+
+impl Halachos {
+    /// The book of the Mishneh Torah this section belongs to.
+    pub const fn sefer(self) -> Sefer {
+        match self {
+            Halachos::YesodeiHaTorah
+            | Halachos::Deos
+            | Halachos::TalmudTorah
+            | Halachos::AvodahZarah
+            | Halachos::Teshuvah => Sefer::Madda,
+            Halachos::KriasShema
+            | Halachos::Tefillah
+            | Halachos::Tefillin
+            | Halachos::Tzitzis
+            | Halachos::Berachos
+            | Halachos::Milah => Sefer::Ahavah,
+            Halachos::Shabbos
+            | Halachos::Eruvin
+            | Halachos::ShevisasAsor
+            | Halachos::ShevisasYomTov
+            | Halachos::ChametzUMatzah
+            | Halachos::ShofarSukkahVeLulav
+            | Halachos::Shekalim
+            | Halachos::KiddushHaChodesh
+            | Halachos::Taaniyos
+            | Halachos::MegillahVeChanukah => Sefer::Zmanim,
+            Halachos::Ishus
+            | Halachos::Gerushin
+            | Halachos::YibumVeChalitzah
+            | Halachos::NaarahBesulah
+            | Halachos::Sotah => Sefer::Nashim,
+            Halachos::IssureiBiah | Halachos::MaachalosAsuros | Halachos::Shechitah => Sefer::Kedushah,
+            Halachos::Shevuos | Halachos::Nedarim | Halachos::Nezirus | Halachos::ArachinVaCharamin => Sefer::Haflaah,
+            Halachos::Kilayim
+            | Halachos::MatnosAniyim
+            | Halachos::Terumos
+            | Halachos::Maaser
+            | Halachos::MaaserSheni
+            | Halachos::Bikkurim
+            | Halachos::ShemitahVeYovel => Sefer::Zeraim,
+            Halachos::BeisHaBechirah
+            | Halachos::KleiHaMikdash
+            | Halachos::BiasHaMikdash
+            | Halachos::IssureiMizbeach
+            | Halachos::MaasehHaKorbanos
+            | Halachos::TemidinUMusafin
+            | Halachos::PesuleiHaMukdashin
+            | Halachos::AvodasYomHaKippurim
+            | Halachos::Meilah => Sefer::Avodah,
+            Halachos::KorbanPesach
+            | Halachos::Chagigah
+            | Halachos::Bechoros
+            | Halachos::Shegagos
+            | Halachos::MechusreiKapparah
+            | Halachos::Temurah => Sefer::Korbanos,
+            Halachos::TumasMeis
+            | Halachos::ParahAdumah
+            | Halachos::TumasTzaraas
+            | Halachos::MetameiMishkavUMoshav
+            | Halachos::ShearAvosHaTumos
+            | Halachos::TumasOchalin
+            | Halachos::Kelim
+            | Halachos::Mikvaos => Sefer::Taharah,
+            Halachos::NizkeiMamon
+            | Halachos::Geneivah
+            | Halachos::GezeilahVaAveidah
+            | Halachos::ChovelUMazik
+            | Halachos::RotzeachUShmirasNefesh => Sefer::Nezikin,
+            Halachos::Mechirah
+            | Halachos::ZechiyahUMattanah
+            | Halachos::Shecheinim
+            | Halachos::SheluchinVeShutafin
+            | Halachos::Avadim => Sefer::Kinyan,
+            Halachos::Sechirus
+            | Halachos::SheilahUPikadon
+            | Halachos::MalvehVeLoveh
+            | Halachos::ToenVeNitan
+            | Halachos::Nachalos => Sefer::Mishpatim,
+            Halachos::Sanhedrin | Halachos::Edus | Halachos::Mamrim | Halachos::Evel | Halachos::Melachim => {
+                Sefer::Shoftim
+            }
+        }
+    }
+
+    /// The number of chapters in this section.
+    pub const fn chapters(self) -> u8 {
+        match self {
+            Halachos::YesodeiHaTorah => 10,
+            Halachos::Deos => 7,
+            Halachos::TalmudTorah => 7,
+            Halachos::AvodahZarah => 12,
+            Halachos::Teshuvah => 10,
+            Halachos::KriasShema => 4,
+            Halachos::Tefillah => 15,
+            Halachos::Tefillin => 10,
+            Halachos::Tzitzis => 3,
+            Halachos::Berachos => 11,
+            Halachos::Milah => 3,
+            Halachos::Shabbos => 30,
+            Halachos::Eruvin => 8,
+            Halachos::ShevisasAsor => 3,
+            Halachos::ShevisasYomTov => 8,
+            Halachos::ChametzUMatzah => 8,
+            Halachos::ShofarSukkahVeLulav => 8,
+            Halachos::Shekalim => 4,
+            Halachos::KiddushHaChodesh => 19,
+            Halachos::Taaniyos => 5,
+            Halachos::MegillahVeChanukah => 4,
+            Halachos::Ishus => 25,
+            Halachos::Gerushin => 13,
+            Halachos::YibumVeChalitzah => 8,
+            Halachos::NaarahBesulah => 3,
+            Halachos::Sotah => 4,
+            Halachos::IssureiBiah => 22,
+            Halachos::MaachalosAsuros => 17,
+            Halachos::Shechitah => 14,
+            Halachos::Shevuos => 12,
+            Halachos::Nedarim => 13,
+            Halachos::Nezirus => 10,
+            Halachos::ArachinVaCharamin => 8,
+            Halachos::Kilayim => 10,
+            Halachos::MatnosAniyim => 10,
+            Halachos::Terumos => 15,
+            Halachos::Maaser => 14,
+            Halachos::MaaserSheni => 11,
+            Halachos::Bikkurim => 12,
+            Halachos::ShemitahVeYovel => 13,
+            Halachos::BeisHaBechirah => 8,
+            Halachos::KleiHaMikdash => 10,
+            Halachos::BiasHaMikdash => 9,
+            Halachos::IssureiMizbeach => 7,
+            Halachos::MaasehHaKorbanos => 19,
+            Halachos::TemidinUMusafin => 10,
+            Halachos::PesuleiHaMukdashin => 19,
+            Halachos::AvodasYomHaKippurim => 5,
+            Halachos::Meilah => 8,
+            Halachos::KorbanPesach => 10,
+            Halachos::Chagigah => 3,
+            Halachos::Bechoros => 8,
+            Halachos::Shegagos => 15,
+            Halachos::MechusreiKapparah => 5,
+            Halachos::Temurah => 4,
+            Halachos::TumasMeis => 25,
+            Halachos::ParahAdumah => 15,
+            Halachos::TumasTzaraas => 16,
+            Halachos::MetameiMishkavUMoshav => 13,
+            Halachos::ShearAvosHaTumos => 20,
+            Halachos::TumasOchalin => 16,
+            Halachos::Kelim => 28,
+            Halachos::Mikvaos => 11,
+            Halachos::NizkeiMamon => 14,
+            Halachos::Geneivah => 9,
+            Halachos::GezeilahVaAveidah => 18,
+            Halachos::ChovelUMazik => 8,
+            Halachos::RotzeachUShmirasNefesh => 13,
+            Halachos::Mechirah => 30,
+            Halachos::ZechiyahUMattanah => 12,
+            Halachos::Shecheinim => 14,
+            Halachos::SheluchinVeShutafin => 10,
+            Halachos::Avadim => 9,
+            Halachos::Sechirus => 13,
+            Halachos::SheilahUPikadon => 8,
+            Halachos::MalvehVeLoveh => 27,
+            Halachos::ToenVeNitan => 16,
+            Halachos::Nachalos => 11,
+            Halachos::Sanhedrin => 26,
+            Halachos::Edus => 22,
+            Halachos::Mamrim => 7,
+            Halachos::Evel => 14,
+            Halachos::Melachim => 12,
+        }
+    }
+}
+
+/// A single daily unit of the Rambam schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RambamUnit {
+    /// One of the 17 daily parts of the introduction and list of mitzvos, learned at the start of each cycle
+    Introduction(u8),
+    /// A chapter of the Mishneh Torah
+    Chapter {
+        /// The section
+        halachos: Halachos,
+        /// The chapter, starting at 1
+        chapter: u8,
+    },
+}
+
+/// The three units learned on a day of the 3-chapter track, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RambamChapters(pub RambamUnit, pub RambamUnit, pub RambamUnit);
+
+/// Every unit of the Rambam schedule, in order.
+pub(crate) fn iter_rambam() -> impl Iterator<Item = RambamUnit> {
+    (1..=RAMBAM_INTRODUCTION_UNITS as u8)
+        .map(RambamUnit::Introduction)
+        .chain(ALL_HALACHOS.iter().flat_map(|halachos| {
+            (1..=halachos.chapters()).map(move |chapter| RambamUnit::Chapter {
+                halachos: *halachos,
+                chapter,
+            })
+        }))
+}
+
+/// Both tracks began their first cycle on 27 Nissan 5744
+fn rambam_cycle_start() -> HebrewDate {
+    from_gregorian_date(1984, 4, 29)
+}
+
+#[derive(Default)]
+/// Calculates the Rambam Yomi schedule of three chapters a day.
+///
+/// Each cycle is 339 days.
+pub struct RambamYomi;
+impl InternalLimudCalculator<RambamChapters> for RambamYomi {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(rambam_cycle_start())
+    }
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<RambamChapters> {
+        let mut iter = iter_rambam();
+        let first = iter.nth(((interval.iteration - 1) * 3) as usize)?;
+        Some(RambamChapters(first, iter.next()?, iter.next()?))
+    }
+    fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
+        hebrew_date.add_days(RAMBAM_UNIT_COUNT / 3 - 1)
+    }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(RAMBAM_UNIT_COUNT)
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.iteration * 3)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index / 3)
+    }
+}
+impl LimudCalculator<RambamChapters> for RambamYomi {}
+
+#[derive(Default)]
+/// Calculates the Rambam Yomi schedule of one chapter a day.
+///
+/// Each cycle is 1017 days, so every cycle spans exactly three cycles of [`RambamYomi`].
+pub struct RambamYomiOneChapter;
+impl InternalLimudCalculator<RambamUnit> for RambamYomiOneChapter {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(rambam_cycle_start())
+    }
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<RambamUnit> {
+        iter_rambam().nth((interval.iteration - 1) as usize)
+    }
+    fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
+        hebrew_date.add_days(RAMBAM_UNIT_COUNT - 1)
+    }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(RAMBAM_UNIT_COUNT)
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.iteration)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        cycle.start_date.add_days(index)
+    }
+}
+impl LimudCalculator<RambamUnit> for RambamYomiOneChapter {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rambam_has_1000_chapters() {
+        let chapters: i32 = ALL_HALACHOS.iter().map(|halachos| halachos.chapters() as i32).sum();
+        assert_eq!(chapters, 1000);
+        assert_eq!(iter_rambam().count() as i32, RAMBAM_UNIT_COUNT);
+        assert_eq!(
+            ALL_HALACHOS
+                .iter()
+                .filter(|halachos| halachos.sefer() == Sefer::Shoftim)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn rambam_yomi_first_day() {
+        let limud = RambamYomi
+            .limud(from_gregorian_date(1984, 4, 29))
+            .expect("limud exists");
+        assert_eq!(
+            limud,
+            RambamChapters(
+                RambamUnit::Introduction(1),
+                RambamUnit::Introduction(2),
+                RambamUnit::Introduction(3)
+            )
+        );
+        assert!(RambamYomi.limud(from_gregorian_date(1984, 4, 28)).is_none());
+    }
+
+    #[test]
+    fn rambam_yomi_end_of_cycle() {
+        // The 42nd cycle ended on 2023-04-22
+        let limud = RambamYomi
+            .limud(from_gregorian_date(2023, 4, 22))
+            .expect("limud exists");
+        assert_eq!(
+            limud,
+            RambamChapters(
+                RambamUnit::Chapter {
+                    halachos: Halachos::Melachim,
+                    chapter: 10
+                },
+                RambamUnit::Chapter {
+                    halachos: Halachos::Melachim,
+                    chapter: 11
+                },
+                RambamUnit::Chapter {
+                    halachos: Halachos::Melachim,
+                    chapter: 12
+                }
+            )
+        );
+        let cycle = RambamYomi
+            .cycle_for(from_gregorian_date(2023, 4, 23))
+            .expect("cycle exists");
+        assert_eq!(cycle.number, 43);
+        assert_eq!(cycle.start_date, from_gregorian_date(2023, 4, 23));
+    }
+
+    #[test]
+    fn rambam_yomi_tracks_stay_in_sync() {
+        // Every third 3-chapter cycle ends together with a 1-chapter cycle
+        let date = from_gregorian_date(2023, 4, 22);
+        assert_eq!(RambamYomi.cycle_for(date).map(|cycle| cycle.number), Some(42));
+        let one_chapter = RambamYomiOneChapter.cycle_for(date).expect("cycle exists");
+        assert_eq!((one_chapter.number, one_chapter.end_date), (14, date));
+        assert_eq!(
+            RambamYomiOneChapter.limud(date),
+            Some(RambamUnit::Chapter {
+                halachos: Halachos::Melachim,
+                chapter: 12
+            })
+        );
+    }
+
+    #[test]
+    fn rambam_yomi_one_chapter_after_introduction() {
+        let date = from_gregorian_date(1984, 4, 29).add_days(17).expect("date is valid");
+        assert_eq!(
+            RambamYomiOneChapter.limud(date),
+            Some(RambamUnit::Chapter {
+                halachos: Halachos::YesodeiHaTorah,
+                chapter: 1
+            })
+        );
+    }
+}
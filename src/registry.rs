@@ -0,0 +1,120 @@
+use crate::{
+    any_limud::DynLimudCalculator, location::Location, AmudYomiBavliDirshu, Chitas, ChumashYomi, DafHashavuaBavli,
+    DafYomiBavli, DafYomiYerushalmiVilna, MishnaYomis, NachYomi, ParshaHashavua, PirkeiAvos, RambamYomi,
+    RambamYomiOneChapter, Tanach929, TehillimMonthly,
+};
+
+/// A calculator that can be shared from a `static`.
+pub type SharedLimudCalculator = dyn DynLimudCalculator + Sync;
+
+/// All registered calculators, keyed by their stable id.
+static REGISTRY: &[(&str, &SharedLimudCalculator)] = &[
+    ("daf-yomi-bavli", &DafYomiBavli {}),
+    ("daf-yomi-yerushalmi-vilna", &DafYomiYerushalmiVilna {}),
+    ("daf-hashavua-bavli", &DafHashavuaBavli {}),
+    ("amud-yomi-dirshu", &AmudYomiBavliDirshu {}),
+    ("mishna-yomis", &MishnaYomis),
+    ("pirkei-avos-israel", &PirkeiAvos::with_location(Location::Israel)),
+    ("pirkei-avos-diaspora", &PirkeiAvos::with_location(Location::Diaspora)),
+    ("parsha-hashavua-israel", &ParshaHashavua::new(Location::Israel)),
+    ("parsha-hashavua-diaspora", &ParshaHashavua::new(Location::Diaspora)),
+    ("chumash-yomi-israel", &ChumashYomi::new(Location::Israel)),
+    ("chumash-yomi-diaspora", &ChumashYomi::new(Location::Diaspora)),
+    ("chitas-israel", &Chitas::new(Location::Israel)),
+    ("chitas-diaspora", &Chitas::new(Location::Diaspora)),
+    ("nach-yomi", &NachYomi),
+    ("929", &Tanach929),
+    ("tehillim-monthly", &TehillimMonthly),
+    ("rambam-3-chapters", &RambamYomi),
+    ("rambam-1-chapter", &RambamYomiOneChapter),
+];
+
+/// A registry of every calculator in this crate, keyed by a stable string id.
+///
+/// This allows calculators to be selected at runtime, e.g. from configuration.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{AnyLimud, LimudRegistry};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let date = Date::try_new_iso(2020, 1, 5).unwrap().to_calendar(Hebrew);
+/// let calculator = LimudRegistry::get("daf-yomi-bavli").unwrap();
+/// assert!(matches!(calculator.limud(date), Some(AnyLimud::Daf(_))));
+/// ```
+pub struct LimudRegistry;
+
+impl LimudRegistry {
+    /// Get the calculator registered under the given id.
+    ///
+    /// # Returns
+    /// The calculator, or `None` if no calculator is registered under this id
+    pub fn get(id: &str) -> Option<&'static SharedLimudCalculator> {
+        REGISTRY
+            .iter()
+            .find(|(entry_id, _)| *entry_id == id)
+            .map(|(_, calculator)| *calculator)
+    }
+
+    /// Iterate over the ids of all registered calculators.
+    pub fn ids() -> impl Iterator<Item = &'static str> {
+        REGISTRY.iter().map(|(id, _)| *id)
+    }
+
+    /// Iterate over all registered calculators along with their ids.
+    pub fn iter() -> impl Iterator<Item = (&'static str, &'static SharedLimudCalculator)> {
+        REGISTRY.iter().copied()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+
+    use crate::{
+        date::{from_gregorian_date, from_hebrew_date},
+        AnyLimud, Daf, PirkeiAvosUnit, Tractate,
+    };
+
+    use super::*;
+
+    #[test]
+    fn registry_get_daf_yomi_bavli() {
+        let test_date = from_gregorian_date(2017, 12, 28);
+        let calculator = LimudRegistry::get("daf-yomi-bavli").expect("calculator exists");
+        let limud = calculator.limud(test_date).expect("limud exists");
+        assert_eq!(
+            limud,
+            AnyLimud::Daf(Daf {
+                tractate: Tractate::Shevuos,
+                page: 30
+            })
+        );
+    }
+
+    #[test]
+    fn registry_unknown_id() {
+        assert!(LimudRegistry::get("daf-yomi-klingon").is_none());
+    }
+
+    #[test]
+    fn registry_ids_are_unique() {
+        for (index, id) in LimudRegistry::ids().enumerate() {
+            assert_eq!(LimudRegistry::ids().position(|other| other == id), Some(index));
+        }
+    }
+
+    #[test]
+    fn registry_pirkei_avos_location() {
+        // 22nd of Nissan 5778 is Shabbos: a limud in Israel, still Pesach outside Israel
+        let test_date = from_hebrew_date(5778, HebrewMonth::Nissan, 22);
+        let israel = LimudRegistry::get("pirkei-avos-israel").expect("calculator exists");
+        let diaspora = LimudRegistry::get("pirkei-avos-diaspora").expect("calculator exists");
+        assert_eq!(
+            israel.limud(test_date),
+            Some(AnyLimud::PirkeiAvos(PirkeiAvosUnit::Single(1)))
+        );
+        assert!(diaspora.limud(test_date).is_none());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{
+    amud_yomi_bavli_dirshu::iter_amud,
+    constants::{
+        BAVLI_DAF_COUNT_MODERN, BAVLI_TOTAL_AMUDIM, MISHNA_COUNT, NACH_CHAPTER_COUNT, RAMBAM_UNIT_COUNT,
+        SHEKALIM_EXPANSION_CYCLE, TANACH_CHAPTER_COUNT, YERUSHALMI_DAF_COUNT,
+    },
+    daf_yomi_bavli, daf_yomi_yerushalmi,
+    mishna_yomis::{iter_mishna, Mishnas},
+    rambam_yomi::{iter_rambam, RambamChapters, RambamUnit},
+    tanach::{iter_nach, iter_tanach, TanachChapter},
+    units::{Amud, Daf, Mishna, Tractate},
+};
+
+/// Number of psalms in Tehillim
+const PSALM_COUNT: usize = 150;
+
+/// An ordered sequence of learning units, e.g. every daf of Shas.
+///
+/// Sequences are used to divide learning over a custom schedule.
+pub trait UnitSequence {
+    /// The learning unit of this sequence
+    type Unit: Copy + PartialEq;
+
+    /// Iterate over every unit of the sequence, in order.
+    fn units(&self) -> impl Iterator<Item = Self::Unit>;
+
+    /// The number of units in the sequence.
+    fn len(&self) -> usize {
+        self.units().count()
+    }
+
+    /// Whether the sequence has no units.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the unit at the given (zero based) index.
+    fn get(&self, index: usize) -> Option<Self::Unit> {
+        self.units().nth(index)
+    }
+
+    /// Find the (zero based) index of the given unit.
+    fn position(&self, unit: &Self::Unit) -> Option<usize> {
+        self.units().position(|other| other == *unit)
+    }
+
+    /// The tractate a unit belongs to, for sequences divided into tractates.
+    fn tractate(&self, _unit: &Self::Unit) -> Option<Tractate> {
+        None
+    }
+}
+
+/// An inclusive range of learning units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnitRange<U> {
+    /// The first unit of the range
+    pub start: U,
+    /// The last unit of the range
+    pub end: U,
+}
+
+/// A limud that covers a contiguous span of units of a sequence.
+pub trait UnitSpan<U> {
+    /// The first unit covered
+    fn first_unit(&self) -> U;
+    /// The last unit covered
+    fn last_unit(&self) -> U;
+}
+
+macro_rules! impl_single_unit_span {
+    ($($unit:ty),*) => {
+        $(
+            impl UnitSpan<$unit> for $unit {
+                fn first_unit(&self) -> $unit {
+                    *self
+                }
+                fn last_unit(&self) -> $unit {
+                    *self
+                }
+            }
+        )*
+    };
+}
+impl_single_unit_span!(Daf, Amud, Mishna, RambamUnit, TanachChapter, u8);
+
+impl UnitSpan<Mishna> for Mishnas {
+    fn first_unit(&self) -> Mishna {
+        self.0
+    }
+    fn last_unit(&self) -> Mishna {
+        self.1
+    }
+}
+
+impl UnitSpan<RambamUnit> for RambamChapters {
+    fn first_unit(&self) -> RambamUnit {
+        self.0
+    }
+    fn last_unit(&self) -> RambamUnit {
+        self.2
+    }
+}
+
+impl<U: Copy> UnitSpan<U> for UnitRange<U> {
+    fn first_unit(&self) -> U {
+        self.start
+    }
+    fn last_unit(&self) -> U {
+        self.end
+    }
+}
+
+/// Every daf of the Babylonian Talmud, in Daf Yomi order.
+///
+/// Shekalim is the 22 daf edition learned since the 8th Daf Yomi cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BavliDafim;
+
+impl UnitSequence for BavliDafim {
+    type Unit = Daf;
+    fn units(&self) -> impl Iterator<Item = Daf> {
+        daf_yomi_bavli::iter_daf(SHEKALIM_EXPANSION_CYCLE)
+    }
+    fn len(&self) -> usize {
+        BAVLI_DAF_COUNT_MODERN as usize
+    }
+    fn tractate(&self, unit: &Daf) -> Option<Tractate> {
+        Some(unit.tractate)
+    }
+}
+
+/// Every amud of the Babylonian Talmud, in Daf Yomi order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BavliAmudim;
+
+impl UnitSequence for BavliAmudim {
+    type Unit = Amud;
+    fn units(&self) -> impl Iterator<Item = Amud> {
+        iter_amud(1)
+    }
+    fn len(&self) -> usize {
+        BAVLI_TOTAL_AMUDIM as usize
+    }
+    fn tractate(&self, unit: &Amud) -> Option<Tractate> {
+        Some(unit.tractate)
+    }
+}
+
+/// Every daf of the Jerusalem Talmud (Vilna Edition), in Daf Yomi order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct YerushalmiDafim;
+
+impl UnitSequence for YerushalmiDafim {
+    type Unit = Daf;
+    fn units(&self) -> impl Iterator<Item = Daf> {
+        daf_yomi_yerushalmi::iter_daf(1)
+    }
+    fn len(&self) -> usize {
+        YERUSHALMI_DAF_COUNT as usize
+    }
+    fn tractate(&self, unit: &Daf) -> Option<Tractate> {
+        Some(unit.tractate)
+    }
+}
+
+/// Every mishna of Shas, in Mishna Yomis order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Mishnayos;
+
+impl UnitSequence for Mishnayos {
+    type Unit = Mishna;
+    fn units(&self) -> impl Iterator<Item = Mishna> {
+        iter_mishna()
+    }
+    fn len(&self) -> usize {
+        MISHNA_COUNT as usize
+    }
+    fn tractate(&self, unit: &Mishna) -> Option<Tractate> {
+        Some(unit.tractate)
+    }
+}
+
+/// Every chapter of the Mishneh Torah, preceded by the 17 parts of its introduction, in Rambam Yomi order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MishnehTorah;
+
+impl UnitSequence for MishnehTorah {
+    type Unit = RambamUnit;
+    fn units(&self) -> impl Iterator<Item = RambamUnit> {
+        iter_rambam()
+    }
+    fn len(&self) -> usize {
+        RAMBAM_UNIT_COUNT as usize
+    }
+}
+
+/// Every chapter of Tanach, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tanach;
+
+impl UnitSequence for Tanach {
+    type Unit = TanachChapter;
+    fn units(&self) -> impl Iterator<Item = TanachChapter> {
+        iter_tanach()
+    }
+    fn len(&self) -> usize {
+        TANACH_CHAPTER_COUNT as usize
+    }
+}
+
+/// Every chapter of Nevi'im and Kesuvim, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Nach;
+
+impl UnitSequence for Nach {
+    type Unit = TanachChapter;
+    fn units(&self) -> impl Iterator<Item = TanachChapter> {
+        iter_nach()
+    }
+    fn len(&self) -> usize {
+        NACH_CHAPTER_COUNT as usize
+    }
+}
+
+/// Every psalm of Tehillim, by number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Psalms;
+
+impl UnitSequence for Psalms {
+    type Unit = u8;
+    fn units(&self) -> impl Iterator<Item = u8> {
+        1..=PSALM_COUNT as u8
+    }
+    fn len(&self) -> usize {
+        PSALM_COUNT
+    }
+    fn get(&self, index: usize) -> Option<u8> {
+        if index < PSALM_COUNT {
+            Some(index as u8 + 1)
+        } else {
+            None
+        }
+    }
+    fn position(&self, unit: &u8) -> Option<usize> {
+        if (1..=PSALM_COUNT as u8).contains(unit) {
+            Some(*unit as usize - 1)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::units::{Side, Tractate};
+
+    use super::*;
+
+    #[test]
+    fn sequence_lengths_match_units() {
+        assert_eq!(BavliDafim.units().count(), BavliDafim.len());
+        assert_eq!(BavliAmudim.units().count(), BavliAmudim.len());
+        assert_eq!(YerushalmiDafim.units().count(), YerushalmiDafim.len());
+        assert_eq!(Mishnayos.units().count(), Mishnayos.len());
+        assert_eq!(Psalms.units().count(), Psalms.len());
+    }
+
+    #[test]
+    fn sequence_position_and_get() {
+        let daf = Daf {
+            tractate: Tractate::Shevuos,
+            page: 30,
+        };
+        let index = BavliDafim.position(&daf).expect("daf exists");
+        assert_eq!(BavliDafim.get(index), Some(daf));
+        assert_eq!(BavliDafim.get(BavliDafim.len()), None);
+        assert_eq!(
+            BavliAmudim.get(1),
+            Some(Amud {
+                tractate: Tractate::Berachos,
+                page: 2,
+                side: Side::Bet
+            })
+        );
+        assert_eq!(Psalms.position(&119), Some(118));
+        assert_eq!(Psalms.position(&151), None);
+    }
+}
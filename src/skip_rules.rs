@@ -0,0 +1,201 @@
+use icu_calendar::types::Weekday;
+
+use crate::{
+    daily_limudim::NoLimudReason,
+    date::{DateExt, HebrewDate},
+    location::Location,
+    zman_calendar::ZmanCalendar,
+};
+
+/// Rules for which days a custom schedule does not learn on.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::SkipRules;
+/// use icu_calendar::types::Weekday;
+///
+/// // No learning on Shabbos, Yom Kippur or Tisha B'Av
+/// let rules = SkipRules::new()
+///     .skip_weekday(Weekday::Saturday)
+///     .skip_yom_kippur_and_tisha_bav();
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SkipRules {
+    weekdays: [bool; 7],
+    yom_kippur_and_tisha_bav: bool,
+    yom_tov: Option<Location>,
+    custom: Option<fn(HebrewDate) -> bool>,
+    zmanim: Option<ZmanCalendar>,
+}
+
+impl SkipRules {
+    /// Rules that skip no days.
+    pub const fn new() -> Self {
+        Self {
+            weekdays: [false; 7],
+            yom_kippur_and_tisha_bav: false,
+            yom_tov: None,
+            custom: None,
+            zmanim: None,
+        }
+    }
+
+    /// Skip every occurrence of the given day of the week.
+    pub fn skip_weekday(mut self, weekday: Weekday) -> Self {
+        self.weekdays[weekday_index(weekday)] = true;
+        self
+    }
+
+    /// Skip Yom Kippur and Tisha B'Av, as Daf Yomi Yerushalmi does.
+    pub fn skip_yom_kippur_and_tisha_bav(mut self) -> Self {
+        self.yom_kippur_and_tisha_bav = true;
+        self
+    }
+
+    /// Skip Yom Tov (including Rosh Hashana and Yom Kippur) as observed in the given location.
+    ///
+    /// Outside Israel, Yom Tov sheni is skipped as well.
+    pub fn skip_yom_tov(mut self, location: Location) -> Self {
+        self.yom_tov = Some(location);
+        self
+    }
+
+    /// Skip bein hazmanim, so the schedule only learns during the given zmanim.
+    pub fn skip_bein_hazmanim(mut self, zmanim: ZmanCalendar) -> Self {
+        self.zmanim = Some(zmanim);
+        self
+    }
+
+    /// Skip every date for which the given predicate returns `true`.
+    ///
+    /// Only one predicate is kept, so calling this again replaces the previous predicate. To skip
+    /// on several conditions, combine them in a single function.
+    pub fn skip_if(mut self, predicate: fn(HebrewDate) -> bool) -> Self {
+        self.custom = Some(predicate);
+        self
+    }
+
+    /// Whether the given date is skipped by these rules.
+    pub fn is_skipped(&self, date: &HebrewDate) -> bool {
+        if self.weekdays[(date.day_of_week_number() - 1) as usize] {
+            return true;
+        }
+        // Yom Kippur and Tisha B'Av fall on the same day in every location
+        if self.yom_kippur_and_tisha_bav && NoLimudReason::for_date(date, Location::Diaspora).is_some() {
+            return true;
+        }
+        if self.yom_tov.is_some_and(|location| date.is_yom_tov(location)) {
+            return true;
+        }
+        if self.zmanim.is_some_and(|zmanim| zmanim.is_bein_hazmanim(date)) {
+            return true;
+        }
+        self.custom.is_some_and(|predicate| predicate(*date))
+    }
+
+    /// Why a skipped date has no learning, given the Yom Kippur or Tisha B'Av on it, if any
+    pub(crate) fn skip_reason(&self, holiday: Option<NoLimudReason>) -> NoLimudReason {
+        match holiday {
+            Some(reason) if self.yom_kippur_and_tisha_bav => reason,
+            Some(NoLimudReason::YomKippur) if self.yom_tov.is_some() => NoLimudReason::YomKippur,
+            _ => NoLimudReason::NotScheduled,
+        }
+    }
+
+    /// Whether any rule depends on the date itself rather than only its day of the week
+    const fn has_date_rules(&self) -> bool {
+        self.yom_kippur_and_tisha_bav || self.yom_tov.is_some() || self.custom.is_some() || self.zmanim.is_some()
+    }
+
+    /// The number of days that are not skipped from `from` until (not including) `date`
+    pub(crate) fn learning_days_between(&self, from: HebrewDate, date: HebrewDate) -> Option<i32> {
+        let days = from.days_until(&date)?.max(0);
+        let mut current = from;
+        let mut count = 0;
+        if !self.has_date_rules() {
+            // Only weekdays are skipped, so whole weeks can be counted at once
+            let per_week = self.weekdays.iter().filter(|skipped| !**skipped).count() as i32;
+            count = days / 7 * per_week;
+            current = from.add_days(days / 7 * 7)?;
+        }
+        while current < date {
+            if !self.is_skipped(&current) {
+                count += 1;
+            }
+            current = current.add_days(1)?;
+        }
+        Some(count)
+    }
+}
+
+/// Index of a weekday, Sunday = 0
+pub(crate) const fn weekday_index(weekday: Weekday) -> usize {
+    match weekday {
+        Weekday::Sunday => 0,
+        Weekday::Monday => 1,
+        Weekday::Tuesday => 2,
+        Weekday::Wednesday => 3,
+        Weekday::Thursday => 4,
+        Weekday::Friday => 5,
+        Weekday::Saturday => 6,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use hebrew_holiday_calendar::HebrewMonth;
+
+    use crate::date::{from_gregorian_date, from_hebrew_date};
+
+    use super::*;
+
+    #[test]
+    fn skip_rules_weekday() {
+        let rules = SkipRules::new().skip_weekday(Weekday::Saturday);
+        // 2020-01-04 is Shabbos
+        assert!(rules.is_skipped(&from_gregorian_date(2020, 1, 4)));
+        assert!(!rules.is_skipped(&from_gregorian_date(2020, 1, 5)));
+    }
+
+    #[test]
+    fn skip_rules_yom_kippur() {
+        let yom_kippur = from_hebrew_date(5778, HebrewMonth::Tishrei, 10);
+        assert!(!SkipRules::new().is_skipped(&yom_kippur));
+        assert!(SkipRules::new().skip_yom_kippur_and_tisha_bav().is_skipped(&yom_kippur));
+    }
+
+    #[test]
+    fn skip_rules_yom_tov_sheni() {
+        let second_day = from_hebrew_date(5778, HebrewMonth::Sivan, 7);
+        assert!(SkipRules::new()
+            .skip_yom_tov(Location::Diaspora)
+            .is_skipped(&second_day));
+        assert!(!SkipRules::new().skip_yom_tov(Location::Israel).is_skipped(&second_day));
+        let shavuos = from_hebrew_date(5778, HebrewMonth::Sivan, 6);
+        assert!(SkipRules::new().skip_yom_tov(Location::Israel).is_skipped(&shavuos));
+    }
+
+    #[test]
+    fn skip_rules_custom() {
+        let rules = SkipRules::new().skip_if(|date| date.day_of_month().0 == 1);
+        assert!(rules.is_skipped(&from_hebrew_date(5778, HebrewMonth::Tishrei, 1)));
+        assert!(!rules.is_skipped(&from_hebrew_date(5778, HebrewMonth::Tishrei, 2)));
+        // A later predicate replaces the earlier one
+        let rules = rules.skip_if(|date| date.day_of_month().0 == 2);
+        assert!(!rules.is_skipped(&from_hebrew_date(5778, HebrewMonth::Tishrei, 1)));
+        assert!(rules.is_skipped(&from_hebrew_date(5778, HebrewMonth::Tishrei, 2)));
+    }
+
+    #[test]
+    fn skip_rules_learning_days_between() {
+        let from = from_gregorian_date(2024, 1, 1);
+        let date = from_gregorian_date(2024, 3, 1);
+        let weekly = SkipRules::new().skip_weekday(Weekday::Saturday);
+        // Counting whole weeks gives the same result as checking every day
+        let daily = weekly.skip_if(|_| false);
+        assert_eq!(weekly.learning_days_between(from, date), Some(52));
+        assert_eq!(daily.learning_days_between(from, date), Some(52));
+        assert_eq!(weekly.learning_days_between(date, from), Some(0));
+    }
+}
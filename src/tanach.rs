@@ -0,0 +1,256 @@
+use core::fmt;
+
+/// One of the three parts of Tanach.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum TanachSection {
+    Torah,
+    Neviim,
+    Kesuvim,
+}
+
+/// A book (sefer) of Tanach.
+///
+/// Shmuel, Melachim and Divrei HaYamim are divided into their two books, and each of Trei
+/// Asar is a book of its own.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum TanachBook {
+    Bereishis,
+    Shemos,
+    Vayikra,
+    Bamidbar,
+    Devarim,
+    Yehoshua,
+    Shoftim,
+    ShmuelI,
+    ShmuelII,
+    MelachimI,
+    MelachimII,
+    Yeshayahu,
+    Yirmiyahu,
+    Yechezkel,
+    Hoshea,
+    Yoel,
+    Amos,
+    Ovadiah,
+    Yonah,
+    Michah,
+    Nachum,
+    Chavakuk,
+    Tzefaniah,
+    Chaggai,
+    Zechariah,
+    Malachi,
+    Tehillim,
+    Mishlei,
+    Iyov,
+    ShirHaShirim,
+    Rus,
+    Eichah,
+    Koheles,
+    Esther,
+    Daniel,
+    Ezra,
+    Nechemiah,
+    DivreiHaYamimI,
+    DivreiHaYamimII,
+}
+
+/// Every book of Tanach, in order.
+pub const ALL_TANACH_BOOKS: [TanachBook; 39] = [
+    TanachBook::Bereishis,
+    TanachBook::Shemos,
+    TanachBook::Vayikra,
+    TanachBook::Bamidbar,
+    TanachBook::Devarim,
+    TanachBook::Yehoshua,
+    TanachBook::Shoftim,
+    TanachBook::ShmuelI,
+    TanachBook::ShmuelII,
+    TanachBook::MelachimI,
+    TanachBook::MelachimII,
+    TanachBook::Yeshayahu,
+    TanachBook::Yirmiyahu,
+    TanachBook::Yechezkel,
+    TanachBook::Hoshea,
+    TanachBook::Yoel,
+    TanachBook::Amos,
+    TanachBook::Ovadiah,
+    TanachBook::Yonah,
+    TanachBook::Michah,
+    TanachBook::Nachum,
+    TanachBook::Chavakuk,
+    TanachBook::Tzefaniah,
+    TanachBook::Chaggai,
+    TanachBook::Zechariah,
+    TanachBook::Malachi,
+    TanachBook::Tehillim,
+    TanachBook::Mishlei,
+    TanachBook::Iyov,
+    TanachBook::ShirHaShirim,
+    TanachBook::Rus,
+    TanachBook::Eichah,
+    TanachBook::Koheles,
+    TanachBook::Esther,
+    TanachBook::Daniel,
+    TanachBook::Ezra,
+    TanachBook::Nechemiah,
+    TanachBook::DivreiHaYamimI,
+    TanachBook::DivreiHaYamimII,
+];
+
+impl TanachBook {
+    /// The part of Tanach this book belongs to.
+    pub const fn section(self) -> TanachSection {
+        match self {
+            TanachBook::Bereishis
+            | TanachBook::Shemos
+            | TanachBook::Vayikra
+            | TanachBook::Bamidbar
+            | TanachBook::Devarim => TanachSection::Torah,
+            TanachBook::Yehoshua
+            | TanachBook::Shoftim
+            | TanachBook::ShmuelI
+            | TanachBook::ShmuelII
+            | TanachBook::MelachimI
+            | TanachBook::MelachimII
+            | TanachBook::Yeshayahu
+            | TanachBook::Yirmiyahu
+            | TanachBook::Yechezkel
+            | TanachBook::Hoshea
+            | TanachBook::Yoel
+            | TanachBook::Amos
+            | TanachBook::Ovadiah
+            | TanachBook::Yonah
+            | TanachBook::Michah
+            | TanachBook::Nachum
+            | TanachBook::Chavakuk
+            | TanachBook::Tzefaniah
+            | TanachBook::Chaggai
+            | TanachBook::Zechariah
+            | TanachBook::Malachi => TanachSection::Neviim,
+            _ => TanachSection::Kesuvim,
+        }
+    }
+
+    /// The number of chapters in this book.
+    pub const fn chapters(self) -> u8 {
+        match self {
+            TanachBook::Bereishis => 50,
+            TanachBook::Shemos => 40,
+            TanachBook::Vayikra => 27,
+            TanachBook::Bamidbar => 36,
+            TanachBook::Devarim => 34,
+            TanachBook::Yehoshua => 24,
+            TanachBook::Shoftim => 21,
+            TanachBook::ShmuelI => 31,
+            TanachBook::ShmuelII => 24,
+            TanachBook::MelachimI => 22,
+            TanachBook::MelachimII => 25,
+            TanachBook::Yeshayahu => 66,
+            TanachBook::Yirmiyahu => 52,
+            TanachBook::Yechezkel => 48,
+            TanachBook::Hoshea => 14,
+            TanachBook::Yoel => 4,
+            TanachBook::Amos => 9,
+            TanachBook::Ovadiah => 1,
+            TanachBook::Yonah => 4,
+            TanachBook::Michah => 7,
+            TanachBook::Nachum => 3,
+            TanachBook::Chavakuk => 3,
+            TanachBook::Tzefaniah => 3,
+            TanachBook::Chaggai => 2,
+            TanachBook::Zechariah => 14,
+            TanachBook::Malachi => 3,
+            TanachBook::Tehillim => 150,
+            TanachBook::Mishlei => 31,
+            TanachBook::Iyov => 42,
+            TanachBook::ShirHaShirim => 8,
+            TanachBook::Rus => 4,
+            TanachBook::Eichah => 5,
+            TanachBook::Koheles => 12,
+            TanachBook::Esther => 10,
+            TanachBook::Daniel => 12,
+            TanachBook::Ezra => 10,
+            TanachBook::Nechemiah => 13,
+            TanachBook::DivreiHaYamimI => 29,
+            TanachBook::DivreiHaYamimII => 36,
+        }
+    }
+}
+
+impl fmt::Display for TanachBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A chapter of Tanach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TanachChapter {
+    /// The book
+    pub book: TanachBook,
+    /// The chapter, starting at 1
+    pub chapter: u8,
+}
+
+impl fmt::Display for TanachChapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.book, self.chapter)
+    }
+}
+
+/// Every chapter of the given books, in order
+fn iter_chapters(books: &'static [TanachBook]) -> impl Iterator<Item = TanachChapter> {
+    books
+        .iter()
+        .flat_map(|book| (1..=book.chapters()).map(move |chapter| TanachChapter { book: *book, chapter }))
+}
+
+/// Every chapter of Tanach, in order.
+pub(crate) fn iter_tanach() -> impl Iterator<Item = TanachChapter> {
+    iter_chapters(&ALL_TANACH_BOOKS)
+}
+
+/// Every chapter of Nevi'im and Kesuvim, in order.
+pub(crate) fn iter_nach() -> impl Iterator<Item = TanachChapter> {
+    iter_chapters(&ALL_TANACH_BOOKS[5..])
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::constants::{NACH_CHAPTER_COUNT, TANACH_CHAPTER_COUNT};
+
+    use super::*;
+
+    #[test]
+    fn tanach_chapter_counts() {
+        assert_eq!(iter_tanach().count() as i32, TANACH_CHAPTER_COUNT);
+        assert_eq!(iter_nach().count() as i32, NACH_CHAPTER_COUNT);
+        let torah: u32 = ALL_TANACH_BOOKS
+            .iter()
+            .filter(|book| book.section() == TanachSection::Torah)
+            .map(|book| book.chapters() as u32)
+            .sum();
+        assert_eq!(torah, 187);
+    }
+
+    #[test]
+    fn nach_begins_with_yehoshua() {
+        let first = iter_nach().next().expect("chapter exists");
+        assert_eq!(
+            first,
+            TanachChapter {
+                book: TanachBook::Yehoshua,
+                chapter: 1
+            }
+        );
+        assert_eq!(first.book.section(), TanachSection::Neviim);
+        assert_eq!(TanachBook::DivreiHaYamimII.section(), TanachSection::Kesuvim);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    constants::TANACH_CHAPTER_COUNT,
+    cycle::Cycle,
+    daily_limudim::NoLimudReason,
+    date::{from_gregorian_date, DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    location::Location,
+    tanach::{iter_tanach, TanachChapter},
+    LimudCalculator,
+};
+
+/// Whether no chapter is learned on this date: Friday, Shabbos, or a Yom Tov in Israel
+fn is_skip_day(date: &HebrewDate) -> bool {
+    date.day_of_week_number() >= 6 || date.is_yom_tov(Location::Israel)
+}
+
+/// The date on which the given number of learning days, starting from `start_date`, is completed
+fn last_learning_day(start_date: HebrewDate, learning_days: i32) -> Option<HebrewDate> {
+    let mut date = start_date;
+    let mut remaining = learning_days;
+    loop {
+        if !is_skip_day(&date) {
+            remaining -= 1;
+            if remaining == 0 {
+                return Some(date);
+            }
+        }
+        date = date.add_days(1)?;
+    }
+}
+
+/// A day of the 929 schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Tanach929Unit {
+    /// The chapter learned
+    pub chapter: TanachChapter,
+    /// The chapter's number in the cycle, from 1 to 929
+    pub number: u16,
+}
+
+#[derive(Default)]
+/// Calculates the 929 schedule of one chapter of Tanach on each weekday from Sunday to Thursday.
+///
+/// No chapter is learned on Friday, Shabbos, or a Yom Tov in Israel, and each cycle continues
+/// from the day after the previous cycle ended. Cycles are counted from the first cycle, which
+/// began on 21 December 2014.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{LimudCalculator, LimudCalendar, Tanach929, TanachBook};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let date = Date::try_new_iso(2014, 12, 25).unwrap().to_calendar(Hebrew);
+/// let limud = date.limud(Tanach929).unwrap();
+/// assert_eq!((limud.chapter.book, limud.chapter.chapter), (TanachBook::Bereishis, 5));
+/// assert_eq!(limud.number, 5);
+/// assert_eq!(Tanach929.cycle_for(date).unwrap().number, 1);
+/// ```
+pub struct Tanach929;
+impl InternalLimudCalculator<Tanach929Unit> for Tanach929 {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(from_gregorian_date(2014, 12, 21))
+    }
+    fn cycle_end_calculation(hebrew_date: HebrewDate, _iteration: Option<i32>) -> Option<HebrewDate> {
+        last_learning_day(hebrew_date, TANACH_CHAPTER_COUNT)
+    }
+    fn cycle_unit_count(_iteration: i32) -> Option<i32> {
+        Some(TANACH_CHAPTER_COUNT)
+    }
+    fn is_skip_interval(&self, interval: &Interval) -> bool {
+        is_skip_day(&interval.start_date)
+    }
+    fn skip_reason(&self, holiday: Option<NoLimudReason>) -> NoLimudReason {
+        // Yom Kippur is skipped as a Yom Tov, but Tisha B'Av is a regular learning day
+        match holiday {
+            Some(NoLimudReason::YomKippur) => NoLimudReason::YomKippur,
+            _ => NoLimudReason::NotScheduled,
+        }
+    }
+    fn unit_for_interval(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<Tanach929Unit> {
+        // Skipped days do not advance the iteration, so it counts the learning days
+        Some(Tanach929Unit {
+            chapter: iter_tanach().nth((interval.iteration - 1) as usize)?,
+            number: interval.iteration as u16,
+        })
+    }
+    fn units_completed(&self, interval: &Interval, _limud_date: &HebrewDate) -> Option<i32> {
+        Some(interval.iteration)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        last_learning_day(cycle.start_date, index + 1)
+    }
+}
+impl LimudCalculator<Tanach929Unit> for Tanach929 {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::tanach::TanachBook;
+
+    use super::*;
+
+    fn chapter(year: i32, month: u8, day: u8) -> Option<TanachChapter> {
+        Tanach929
+            .limud(from_gregorian_date(year, month, day))
+            .map(|unit| unit.chapter)
+    }
+
+    #[test]
+    fn tanach_929_first_week() {
+        assert_eq!(
+            chapter(2014, 12, 21),
+            Some(TanachChapter {
+                book: TanachBook::Bereishis,
+                chapter: 1
+            })
+        );
+        assert!(chapter(2014, 12, 26).is_none());
+        assert!(chapter(2014, 12, 27).is_none());
+        assert_eq!(
+            chapter(2014, 12, 28),
+            Some(TanachChapter {
+                book: TanachBook::Bereishis,
+                chapter: 6
+            })
+        );
+    }
+
+    #[test]
+    fn tanach_929_skips_yom_tov() {
+        // Shavuos 5776 was on Sunday 2016-06-12
+        assert!(chapter(2016, 6, 12).is_none());
+        let before = Tanach929.limud(from_gregorian_date(2016, 6, 9)).expect("limud exists");
+        let after = Tanach929.limud(from_gregorian_date(2016, 6, 13)).expect("limud exists");
+        assert_eq!(after.number, before.number + 1);
+    }
+
+    #[test]
+    fn tanach_929_cycles() {
+        let cycle = Tanach929
+            .cycle_for(from_gregorian_date(2014, 12, 21))
+            .expect("cycle exists");
+        assert_eq!(cycle.unit_count, TANACH_CHAPTER_COUNT);
+        let last = Tanach929.limud(cycle.end_date).expect("limud exists");
+        assert_eq!(last.number, 929);
+        assert_eq!(
+            last.chapter,
+            TanachChapter {
+                book: TanachBook::DivreiHaYamimII,
+                chapter: 36
+            }
+        );
+        let next = Tanach929.cycle_for(cycle.end_date.add_days(1).expect("date is valid"));
+        assert_eq!(next.map(|cycle| cycle.number), Some(2));
+    }
+}
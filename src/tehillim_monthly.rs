@@ -177,4 +177,9 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn tehillim_monthly_has_no_numbered_cycles() {
+        assert!(TehillimMonthly.cycles().next().is_none());
+    }
 }
@@ -1,3 +1,5 @@
+use core::fmt;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[allow(missing_docs)]
@@ -67,6 +69,159 @@ pub enum Tractate {
     Uktzin,
 }
 
+impl Tractate {
+    /// The name of this tractate, e.g. "Bava Metzia".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Tractate::Berachos => "Berachos",
+            Tractate::Peah => "Peah",
+            Tractate::Demai => "Demai",
+            Tractate::Kilayim => "Kilayim",
+            Tractate::Sheviis => "Sheviis",
+            Tractate::Terumos => "Terumos",
+            Tractate::Maasros => "Maasros",
+            Tractate::MaaserSheni => "Maaser Sheni",
+            Tractate::Chalah => "Chalah",
+            Tractate::Orlah => "Orlah",
+            Tractate::Bikurim => "Bikurim",
+            Tractate::Shabbos => "Shabbos",
+            Tractate::Eruvin => "Eruvin",
+            Tractate::Pesachim => "Pesachim",
+            Tractate::Shekalim => "Shekalim",
+            Tractate::Yoma => "Yoma",
+            Tractate::Sukkah => "Sukkah",
+            Tractate::Beitzah => "Beitzah",
+            Tractate::RoshHashanah => "Rosh Hashanah",
+            Tractate::Taanis => "Taanis",
+            Tractate::Megillah => "Megillah",
+            Tractate::MoedKatan => "Moed Katan",
+            Tractate::Chagigah => "Chagigah",
+            Tractate::Yevamos => "Yevamos",
+            Tractate::Kesubos => "Kesubos",
+            Tractate::Nedarim => "Nedarim",
+            Tractate::Nazir => "Nazir",
+            Tractate::Sotah => "Sotah",
+            Tractate::Gitin => "Gitin",
+            Tractate::Kiddushin => "Kiddushin",
+            Tractate::BavaKamma => "Bava Kamma",
+            Tractate::BavaMetzia => "Bava Metzia",
+            Tractate::BavaBasra => "Bava Basra",
+            Tractate::Sanhedrin => "Sanhedrin",
+            Tractate::Makkos => "Makkos",
+            Tractate::Shevuos => "Shevuos",
+            Tractate::Eduyos => "Eduyos",
+            Tractate::AvodahZarah => "Avodah Zarah",
+            Tractate::Avos => "Avos",
+            Tractate::Horiyos => "Horiyos",
+            Tractate::Zevachim => "Zevachim",
+            Tractate::Menachos => "Menachos",
+            Tractate::Chullin => "Chullin",
+            Tractate::Bechoros => "Bechoros",
+            Tractate::Arachin => "Arachin",
+            Tractate::Temurah => "Temurah",
+            Tractate::Kerisos => "Kerisos",
+            Tractate::Meilah => "Meilah",
+            Tractate::Tamid => "Tamid",
+            Tractate::Midos => "Midos",
+            Tractate::Kinnim => "Kinnim",
+            Tractate::Keilim => "Keilim",
+            Tractate::Ohalos => "Ohalos",
+            Tractate::Negaim => "Negaim",
+            Tractate::Parah => "Parah",
+            Tractate::Taharos => "Taharos",
+            Tractate::Mikvaos => "Mikvaos",
+            Tractate::Niddah => "Niddah",
+            Tractate::Machshirin => "Machshirin",
+            Tractate::Zavim => "Zavim",
+            Tractate::TevulYom => "Tevul Yom",
+            Tractate::Yadayim => "Yadayim",
+            Tractate::Uktzin => "Uktzin",
+        }
+    }
+
+    /// The seder of the Mishna this tractate belongs to.
+    pub const fn seder(self) -> Seder {
+        match self {
+            Tractate::Berachos
+            | Tractate::Peah
+            | Tractate::Demai
+            | Tractate::Kilayim
+            | Tractate::Sheviis
+            | Tractate::Terumos
+            | Tractate::Maasros
+            | Tractate::MaaserSheni
+            | Tractate::Chalah
+            | Tractate::Orlah
+            | Tractate::Bikurim => Seder::Zeraim,
+            Tractate::Shabbos
+            | Tractate::Eruvin
+            | Tractate::Pesachim
+            | Tractate::Shekalim
+            | Tractate::Yoma
+            | Tractate::Sukkah
+            | Tractate::Beitzah
+            | Tractate::RoshHashanah
+            | Tractate::Taanis
+            | Tractate::Megillah
+            | Tractate::MoedKatan
+            | Tractate::Chagigah => Seder::Moed,
+            Tractate::Yevamos
+            | Tractate::Kesubos
+            | Tractate::Nedarim
+            | Tractate::Nazir
+            | Tractate::Sotah
+            | Tractate::Gitin
+            | Tractate::Kiddushin => Seder::Nashim,
+            Tractate::BavaKamma
+            | Tractate::BavaMetzia
+            | Tractate::BavaBasra
+            | Tractate::Sanhedrin
+            | Tractate::Makkos
+            | Tractate::Shevuos
+            | Tractate::Eduyos
+            | Tractate::AvodahZarah
+            | Tractate::Avos
+            | Tractate::Horiyos => Seder::Nezikin,
+            Tractate::Zevachim
+            | Tractate::Menachos
+            | Tractate::Chullin
+            | Tractate::Bechoros
+            | Tractate::Arachin
+            | Tractate::Temurah
+            | Tractate::Kerisos
+            | Tractate::Meilah
+            | Tractate::Tamid
+            | Tractate::Midos
+            | Tractate::Kinnim => Seder::Kodashim,
+            Tractate::Keilim
+            | Tractate::Ohalos
+            | Tractate::Negaim
+            | Tractate::Parah
+            | Tractate::Taharos
+            | Tractate::Mikvaos
+            | Tractate::Niddah
+            | Tractate::Machshirin
+            | Tractate::Zavim
+            | Tractate::TevulYom
+            | Tractate::Yadayim
+            | Tractate::Uktzin => Seder::Taharos,
+        }
+    }
+}
+
+/// One of the six orders (sedarim) of the Mishna.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(missing_docs)]
+pub enum Seder {
+    Zeraim,
+    Moed,
+    Nashim,
+    Nezikin,
+    Kodashim,
+    Taharos,
+}
+
 pub const ALL_TRACTATES: [Tractate; 63] = [
     Tractate::Berachos,
     Tractate::Peah,
@@ -302,3 +457,31 @@ pub struct Mishna {
     pub chapter: usize,
     pub mishna: u16,
 }
+
+impl fmt::Display for Tractate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for Daf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.tractate, self.page)
+    }
+}
+
+impl fmt::Display for Amud {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            Side::Aleph => 'a',
+            Side::Bet => 'b',
+        };
+        write!(f, "{} {}{}", self.tractate, self.page, side)
+    }
+}
+
+impl fmt::Display for Mishna {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}:{}", self.tractate, self.chapter, self.mishna)
+    }
+}
@@ -0,0 +1,236 @@
+use icu_calendar::types::Weekday;
+
+use crate::{
+    cycle::Cycle,
+    date::{DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    sequence::{UnitRange, UnitSequence},
+    skip_rules::weekday_index,
+    LimudCalculator,
+};
+
+/// Learns a unit sequence over and over, advancing only on selected days of the week.
+///
+/// Each day of the week learns a fixed number of units, e.g. one daf Sunday to Wednesday,
+/// two on Thursday and none on Friday and Shabbos. When the sequence is finished, the next
+/// cycle begins the following day. The last day of a cycle learns only the units
+/// left in the sequence.
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{BavliDafim, LimudCalendar, WeekdaySchedule};
+/// use icu_calendar::{cal::Hebrew, types::Weekday, Date};
+///
+/// // 2024-01-07 is a Sunday
+/// let start = Date::try_new_iso(2024, 1, 7).unwrap().to_calendar(Hebrew);
+/// let schedule = WeekdaySchedule::sunday_to_thursday(BavliDafim, start).units_on(Weekday::Thursday, 2);
+/// let thursday = Date::try_new_iso(2024, 1, 11).unwrap().to_calendar(Hebrew);
+/// let friday = Date::try_new_iso(2024, 1, 12).unwrap().to_calendar(Hebrew);
+/// let limud = thursday.limud(schedule).unwrap();
+/// assert_eq!((limud.start.page, limud.end.page), (6, 7));
+/// assert!(friday.limud(schedule).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WeekdaySchedule<S: UnitSequence> {
+    sequence: S,
+    start_date: HebrewDate,
+    units_per_day: [u8; 7],
+}
+
+impl<S: UnitSequence> WeekdaySchedule<S> {
+    /// Learn one unit of `sequence` every day, starting on `start_date`.
+    pub fn new(sequence: S, start_date: HebrewDate) -> Self {
+        Self {
+            sequence,
+            start_date,
+            units_per_day: [1; 7],
+        }
+    }
+
+    /// Learn one unit of `sequence` on Sunday to Thursday, starting on `start_date`.
+    pub fn sunday_to_thursday(sequence: S, start_date: HebrewDate) -> Self {
+        Self::new(sequence, start_date)
+            .units_on(Weekday::Friday, 0)
+            .units_on(Weekday::Saturday, 0)
+    }
+
+    /// Learn the given number of units on every occurrence of a day of the week.
+    ///
+    /// A count of 0 skips the day. A schedule that skips every day of the week never learns
+    /// anything, so it has no cycles and every date's limud is `None`.
+    pub fn units_on(mut self, weekday: Weekday, count: u8) -> Self {
+        self.units_per_day[weekday_index(weekday)] = count;
+        self
+    }
+
+    /// The number of units learned on the given date's day of the week
+    fn units_for(&self, date: &HebrewDate) -> usize {
+        self.units_per_day[(date.day_of_week_number() - 1) as usize] as usize
+    }
+
+    /// The number of units learned from `from` until (not including) `date`
+    fn units_before(&self, from: HebrewDate, date: HebrewDate) -> Option<usize> {
+        let days = from.days_until(&date)?.max(0);
+        let per_week: usize = self.units_per_day.iter().map(|count| *count as usize).sum();
+        let mut units = (days / 7) as usize * per_week;
+        let mut current = from.add_days(days / 7 * 7)?;
+        while current < date {
+            units += self.units_for(&current);
+            current = current.add_days(1)?;
+        }
+        Some(units)
+    }
+
+    /// The last day of the cycle that begins on the given date
+    fn cycle_end(&self, start: HebrewDate) -> Option<HebrewDate> {
+        let per_week: usize = self.units_per_day.iter().map(|count| *count as usize).sum();
+        if per_week == 0 || self.sequence.is_empty() {
+            return None;
+        }
+        let len = self.sequence.len();
+        let full_weeks = (len - 1) / per_week;
+        let mut remaining = len - full_weeks * per_week;
+        let mut date = start.add_days(full_weeks as i32 * 7)?;
+        loop {
+            remaining = remaining.saturating_sub(self.units_for(&date));
+            if remaining == 0 {
+                return Some(date);
+            }
+            date = date.add_days(1)?;
+        }
+    }
+}
+
+impl<S: UnitSequence> InternalLimudCalculator<UnitRange<S::Unit>> for WeekdaySchedule<S> {
+    fn cycle_finder(&self) -> CycleFinder {
+        CycleFinder::Initial(self.start_date)
+    }
+
+    fn find_cycle(&self, date: HebrewDate) -> Option<Cycle> {
+        if date < self.start_date {
+            return None;
+        }
+        let mut start_date = self.start_date;
+        let mut iteration = 1;
+        loop {
+            let end_date = self.cycle_end(start_date)?;
+            if date <= end_date {
+                return Some(Cycle {
+                    start_date,
+                    end_date,
+                    iteration: Some(iteration),
+                });
+            }
+            start_date = end_date.add_days(1)?;
+            iteration += 1;
+        }
+    }
+
+    fn is_skip_interval(&self, interval: &Interval) -> bool {
+        self.units_for(&interval.start_date) == 0
+    }
+
+    fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<UnitRange<S::Unit>> {
+        let offset = self.units_before(interval.cycle.start_date, *limud_date)?;
+        let count = self
+            .units_for(limud_date)
+            .min(self.sequence.len().saturating_sub(offset));
+        if count == 0 {
+            return None;
+        }
+        Some(UnitRange {
+            start: self.sequence.get(offset)?,
+            end: self.sequence.get(offset + count - 1)?,
+        })
+    }
+}
+impl<S: UnitSequence> LimudCalculator<UnitRange<S::Unit>> for WeekdaySchedule<S> {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        date::from_gregorian_date,
+        sequence::{BavliDafim, Psalms},
+    };
+
+    use super::*;
+
+    #[test]
+    fn weekday_schedule_sunday_to_thursday() {
+        // 2024-01-07 is a Sunday
+        let schedule = WeekdaySchedule::sunday_to_thursday(BavliDafim, from_gregorian_date(2024, 1, 7));
+        let limud = schedule.limud(from_gregorian_date(2024, 1, 14)).expect("limud exists");
+        assert_eq!(limud.start, limud.end);
+        assert_eq!(limud.start.page, 7);
+        assert!(schedule.limud(from_gregorian_date(2024, 1, 13)).is_none());
+        assert!(schedule.limud(from_gregorian_date(2024, 1, 6)).is_none());
+    }
+
+    #[test]
+    fn weekday_schedule_doubles_up() {
+        let schedule =
+            WeekdaySchedule::sunday_to_thursday(Psalms, from_gregorian_date(2024, 1, 7)).units_on(Weekday::Thursday, 2);
+        let thursday = schedule.limud(from_gregorian_date(2024, 1, 11)).expect("limud exists");
+        assert_eq!(thursday, UnitRange { start: 5, end: 6 });
+        let sunday = schedule.limud(from_gregorian_date(2024, 1, 14)).expect("limud exists");
+        assert_eq!(sunday, UnitRange { start: 7, end: 7 });
+    }
+
+    #[test]
+    fn weekday_schedule_new_cycle() {
+        // 6 psalms a week: 150 psalms end on the 25th week's Thursday
+        let start = from_gregorian_date(2024, 1, 7);
+        let schedule = WeekdaySchedule::sunday_to_thursday(Psalms, start).units_on(Weekday::Thursday, 2);
+        let cycle = schedule.find_cycle(start).expect("cycle exists");
+        assert_eq!(cycle.end_date, from_gregorian_date(2024, 6, 27));
+        let last = schedule.limud(cycle.end_date).expect("limud exists");
+        assert_eq!(last, UnitRange { start: 149, end: 150 });
+        let next = schedule.limud(from_gregorian_date(2024, 6, 30)).expect("limud exists");
+        assert_eq!(next, UnitRange { start: 1, end: 1 });
+        let next_cycle = schedule
+            .find_cycle(from_gregorian_date(2024, 6, 30))
+            .expect("cycle exists");
+        assert_eq!(next_cycle.iteration, Some(2));
+        assert_eq!(next_cycle.start_date, from_gregorian_date(2024, 6, 28));
+    }
+
+    #[test]
+    fn weekday_schedule_no_learning_days() {
+        let start = from_gregorian_date(2024, 1, 7);
+        let schedule = [
+            Weekday::Sunday,
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+        ]
+        .into_iter()
+        .fold(
+            WeekdaySchedule::sunday_to_thursday(Psalms, start),
+            |schedule, weekday| schedule.units_on(weekday, 0),
+        );
+        assert!(schedule.find_cycle(start).is_none());
+        assert!(schedule.limud(start).is_none());
+        assert!(schedule.progress(start).is_none());
+    }
+
+    #[test]
+    fn weekday_schedule_weekly() {
+        // A single psalm each Shabbos
+        let start = from_gregorian_date(2024, 1, 7);
+        let schedule = WeekdaySchedule::new(Psalms, start)
+            .units_on(Weekday::Sunday, 0)
+            .units_on(Weekday::Monday, 0)
+            .units_on(Weekday::Tuesday, 0)
+            .units_on(Weekday::Wednesday, 0)
+            .units_on(Weekday::Thursday, 0)
+            .units_on(Weekday::Friday, 0);
+        assert_eq!(
+            schedule.limud(from_gregorian_date(2024, 1, 20)),
+            Some(UnitRange { start: 2, end: 2 })
+        );
+        assert!(schedule.limud(from_gregorian_date(2024, 1, 19)).is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth};
+use icu_calendar::{cal::Hebrew, Date};
+
+use crate::date::{DateExt, HebrewDate};
+
+/// Calculate the yahrzeit (Hebrew anniversary of a death) in a given Hebrew year.
+///
+/// Follows the common rules:
+/// - A death on 30 Cheshvan or 30 Kislev is observed on the 29th when the month had only
+///   29 days in the first year after the death, and otherwise on the 1st of the next month
+///   in years the month has 29 days.
+/// - A death in Adar II is observed in Adar in years without a second Adar.
+/// - A death in Adar of a year without a second Adar is observed in Adar I.
+/// - A death on 30 Adar I is observed on 30 Shevat in years without a second Adar.
+///
+/// # Returns
+/// The yahrzeit, or `None` if `year` is not after the year of death
+pub fn yahrzeit(date_of_death: HebrewDate, year: i32) -> Option<HebrewDate> {
+    let death_year = date_of_death.year().extended_year();
+    if year <= death_year {
+        return None;
+    }
+    let month = date_of_death.hebrew_month();
+    let day = date_of_death.day_of_month().0;
+    let (month, day) = match (month, day) {
+        (HebrewMonth::Cheshvan | HebrewMonth::Kislev, 30) if month_length(death_year + 1, month)? < 30 => (month, 29),
+        (HebrewMonth::AdarII, _) if !is_leap_year(year)? => (HebrewMonth::Adar, day),
+        (HebrewMonth::Adar, 30) if !is_leap_year(year)? => (HebrewMonth::Shevat, 30),
+        _ => (month, day),
+    };
+    if day > month_length(year, month)? {
+        // The 30th does not exist this year, so it is observed on the 1st of the next month
+        return Date::<Hebrew>::from_hebrew_date(year, month, day - 1).ok()?.add_days(1);
+    }
+    Date::<Hebrew>::from_hebrew_date(year, month, day).ok()
+}
+
+fn month_length(year: i32, month: HebrewMonth) -> Option<u8> {
+    Some(Date::<Hebrew>::from_hebrew_date(year, month, 1).ok()?.days_in_month())
+}
+
+fn is_leap_year(year: i32) -> Option<bool> {
+    Some(
+        Date::<Hebrew>::from_hebrew_date(year, HebrewMonth::Tishrei, 1)
+            .ok()?
+            .is_in_leap_year(),
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::date::from_hebrew_date;
+
+    use super::*;
+
+    #[test]
+    fn yahrzeit_same_date() {
+        let death = from_hebrew_date(5780, HebrewMonth::Nissan, 15);
+        assert_eq!(
+            yahrzeit(death, 5785),
+            Some(from_hebrew_date(5785, HebrewMonth::Nissan, 15))
+        );
+        assert_eq!(yahrzeit(death, 5780), None);
+    }
+
+    #[test]
+    fn yahrzeit_adar() {
+        // 5784 is a leap year, 5785 is not
+        let adar_ii = from_hebrew_date(5784, HebrewMonth::AdarII, 10);
+        assert_eq!(
+            yahrzeit(adar_ii, 5785),
+            Some(from_hebrew_date(5785, HebrewMonth::Adar, 10))
+        );
+        assert_eq!(
+            yahrzeit(adar_ii, 5787),
+            Some(from_hebrew_date(5787, HebrewMonth::AdarII, 10))
+        );
+        let adar_i_30 = from_hebrew_date(5784, HebrewMonth::Adar, 30);
+        assert_eq!(
+            yahrzeit(adar_i_30, 5785),
+            Some(from_hebrew_date(5785, HebrewMonth::Shevat, 30))
+        );
+        let adar = from_hebrew_date(5785, HebrewMonth::Adar, 10);
+        assert_eq!(
+            yahrzeit(adar, 5787),
+            Some(from_hebrew_date(5787, HebrewMonth::Adar, 10))
+        );
+    }
+
+    #[test]
+    fn yahrzeit_cheshvan_30() {
+        // Cheshvan has 30 days in 5783, 5785 and 5788, and 29 days in 5784 and 5789
+        let death = from_hebrew_date(5783, HebrewMonth::Cheshvan, 30);
+        assert_eq!(
+            yahrzeit(death, 5784),
+            Some(from_hebrew_date(5784, HebrewMonth::Cheshvan, 29))
+        );
+        assert_eq!(
+            yahrzeit(death, 5785),
+            Some(from_hebrew_date(5785, HebrewMonth::Cheshvan, 29))
+        );
+        let death = from_hebrew_date(5787, HebrewMonth::Cheshvan, 30);
+        assert_eq!(
+            yahrzeit(death, 5788),
+            Some(from_hebrew_date(5788, HebrewMonth::Cheshvan, 30))
+        );
+        assert_eq!(
+            yahrzeit(death, 5789),
+            Some(from_hebrew_date(5789, HebrewMonth::Kislev, 1))
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use hebrew_holiday_calendar::{HebrewHolidayCalendar, HebrewMonth};
+use icu_calendar::{cal::Hebrew, Date};
+
+use crate::{
+    annotations::Annotations,
+    cycle::Cycle,
+    daily_limudim::NoLimudReason,
+    date::{DateExt, HebrewDate},
+    interval::Interval,
+    limud_calculator::{CycleFinder, InternalLimudCalculator},
+    units::Tractate,
+    LimudCalculator,
+};
+
+/// One of the yeshiva terms (zmanim) of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Zman {
+    /// Elul zman, from Rosh Chodesh Elul until Yom Kippur
+    Elul,
+    /// Winter zman, from after Sukkos until Nissan
+    Winter,
+    /// Summer zman, from after Pesach until Tisha B'Av
+    Summer,
+}
+
+impl Zman {
+    /// Every zman, in the order they begin in the Hebrew year
+    const ALL: [Zman; 3] = [Zman::Winter, Zman::Summer, Zman::Elul];
+
+    const fn index(self) -> usize {
+        match self {
+            Zman::Winter => 0,
+            Zman::Summer => 1,
+            Zman::Elul => 2,
+        }
+    }
+}
+
+/// A day of the Hebrew year on which a zman begins or ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanEdge {
+    /// The Hebrew month
+    pub month: HebrewMonth,
+    /// The day of the month
+    pub day: u8,
+}
+
+impl ZmanEdge {
+    /// Create an edge on the given day of a Hebrew month.
+    pub const fn new(month: HebrewMonth, day: u8) -> Self {
+        Self { month, day }
+    }
+}
+
+/// A single occurrence of a zman.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanPeriod {
+    /// The zman
+    pub zman: Zman,
+    /// The first day of the zman
+    pub start_date: HebrewDate,
+    /// The last day of the zman
+    pub end_date: HebrewDate,
+}
+
+/// The yeshiva terms (zmanim) of the year, and the breaks (bein hazmanim) between them.
+///
+/// Each zman begins on its start edge and ends on the day before its end edge. By default:
+/// - Elul zman runs from 1 Elul until the day before Yom Kippur
+/// - Winter zman runs from 1 Cheshvan until the end of Adar
+/// - Summer zman runs from 1 Iyar until the day before Tisha B'Av
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{Zman, ZmanCalendar};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let zmanim = ZmanCalendar::new();
+/// // Sukkos is bein hazmanim, and winter zman starts after it
+/// let sukkos = Date::try_new_iso(2024, 10, 20).unwrap().to_calendar(Hebrew);
+/// assert!(zmanim.is_bein_hazmanim(&sukkos));
+/// assert_eq!(zmanim.next_zman(&sukkos).unwrap().zman, Zman::Winter);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZmanCalendar {
+    edges: [(ZmanEdge, ZmanEdge); 3],
+}
+
+impl Default for ZmanCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZmanCalendar {
+    /// The standard zmanim.
+    pub const fn new() -> Self {
+        Self {
+            edges: [
+                (
+                    ZmanEdge::new(HebrewMonth::Cheshvan, 1),
+                    ZmanEdge::new(HebrewMonth::Nissan, 1),
+                ),
+                (ZmanEdge::new(HebrewMonth::Iyar, 1), ZmanEdge::new(HebrewMonth::Av, 9)),
+                (
+                    ZmanEdge::new(HebrewMonth::Elul, 1),
+                    ZmanEdge::new(HebrewMonth::Tishrei, 10),
+                ),
+            ],
+        }
+    }
+
+    /// Change when a zman begins and ends.
+    ///
+    /// The zman begins on `start` and ends on the day before `end`.
+    pub fn edges(mut self, zman: Zman, start: ZmanEdge, end: ZmanEdge) -> Self {
+        self.edges[zman.index()] = (start, end);
+        self
+    }
+
+    /// Find the zman that the given date falls in.
+    ///
+    /// # Returns
+    /// The zman, or `None` if the date is bein hazmanim
+    pub fn zman(&self, date: &HebrewDate) -> Option<ZmanPeriod> {
+        self.periods_around(date)
+            .find(|period| period.start_date <= *date && *date <= period.end_date)
+    }
+
+    /// Whether the given date is bein hazmanim (between zmanim).
+    pub fn is_bein_hazmanim(&self, date: &HebrewDate) -> bool {
+        self.zman(date).is_none()
+    }
+
+    /// Find the next zman that begins after the given date.
+    pub fn next_zman(&self, date: &HebrewDate) -> Option<ZmanPeriod> {
+        self.periods_around(date)
+            .filter(|period| period.start_date > *date)
+            .min_by_key(|period| period.start_date)
+    }
+
+    /// The zmanim that begin in the Hebrew years before, of and after the date
+    fn periods_around(&self, date: &HebrewDate) -> impl Iterator<Item = ZmanPeriod> + '_ {
+        let year = date.year().extended_year();
+        (year - 1..=year + 1).flat_map(move |year| Zman::ALL.iter().filter_map(move |zman| self.period(*zman, year)))
+    }
+
+    /// The zman that begins in the given Hebrew year
+    fn period(&self, zman: Zman, year: i32) -> Option<ZmanPeriod> {
+        let (start, end) = self.edges[zman.index()];
+        let start_date = Date::<Hebrew>::from_hebrew_date(year, start.month, start.day).ok()?;
+        let mut end_date = Date::<Hebrew>::from_hebrew_date(year, end.month, end.day).ok()?;
+        if end_date <= start_date {
+            end_date = Date::<Hebrew>::from_hebrew_date(year + 1, end.month, end.day).ok()?;
+        }
+        Some(ZmanPeriod {
+            zman,
+            start_date,
+            end_date: end_date.add_days(-1)?,
+        })
+    }
+}
+
+/// Runs any calculator only during zman, dropping the units it schedules bein hazmanim.
+///
+/// The wrapped schedule keeps running through bein hazmanim, so the units learned after a break
+/// are the same as the public schedule's, and progress counts the dropped units as if they were
+/// learned. To pause a custom schedule during bein hazmanim instead, so that it resumes where it
+/// stopped, use [`SkipRules::skip_bein_hazmanim`](crate::SkipRules::skip_bein_hazmanim) with a
+/// [`PacePlanner`](crate::PacePlanner).
+///
+/// ## Example
+/// ```
+/// use limudim_calendar::{DafYomiBavli, DropBeinHazmanim, LimudCalendar, ZmanCalendar};
+/// use icu_calendar::{cal::Hebrew, Date};
+///
+/// let calculator = DropBeinHazmanim::new(DafYomiBavli::default(), ZmanCalendar::new());
+/// let sukkos = Date::try_new_iso(2024, 10, 20).unwrap().to_calendar(Hebrew);
+/// assert!(sukkos.limud(calculator).is_none());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DropBeinHazmanim<C> {
+    calculator: C,
+    zmanim: ZmanCalendar,
+}
+
+impl<C> DropBeinHazmanim<C> {
+    /// Run `calculator` only during the zmanim of `zmanim`, dropping its units bein hazmanim.
+    pub fn new(calculator: C, zmanim: ZmanCalendar) -> Self {
+        Self { calculator, zmanim }
+    }
+}
+
+impl<T, C: LimudCalculator<T>> InternalLimudCalculator<T> for DropBeinHazmanim<C> {
+    fn find_interval(&self, limud_date: HebrewDate) -> Option<Interval> {
+        self.calculator.find_interval(limud_date)
+    }
+    fn cycle_finder(&self) -> CycleFinder {
+        self.calculator.cycle_finder()
+    }
+    fn find_cycle(&self, date: HebrewDate) -> Option<Cycle> {
+        self.calculator.find_cycle(date)
+    }
+    fn cycle_end_calculation(hebrew_date: HebrewDate, iteration: Option<i32>) -> Option<HebrewDate> {
+        C::cycle_end_calculation(hebrew_date, iteration)
+    }
+    fn cycle_unit_count(iteration: i32) -> Option<i32> {
+        C::cycle_unit_count(iteration)
+    }
+    fn interval_end_calculation(cycle: Cycle, hebrew_date: HebrewDate) -> Option<HebrewDate> {
+        C::interval_end_calculation(cycle, hebrew_date)
+    }
+    fn is_skip_interval(&self, interval: &Interval) -> bool {
+        self.calculator.is_skip_interval(interval)
+    }
+    fn skip_reason(&self, holiday: Option<NoLimudReason>) -> NoLimudReason {
+        self.calculator.skip_reason(holiday)
+    }
+    fn unit_for_interval(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<T> {
+        if self.zmanim.is_bein_hazmanim(limud_date) {
+            return None;
+        }
+        self.calculator.unit_for_interval(interval, limud_date)
+    }
+    fn annotate(&self, unit: &T, interval: &Interval, annotations: &mut Annotations) {
+        self.calculator.annotate(unit, interval, annotations);
+    }
+    fn tractates(&self) -> &'static [Tractate] {
+        self.calculator.tractates()
+    }
+    fn tractate_unit_count(tractate: Tractate, iteration: i32) -> i32 {
+        C::tractate_unit_count(tractate, iteration)
+    }
+    fn units_completed(&self, interval: &Interval, limud_date: &HebrewDate) -> Option<i32> {
+        self.calculator.units_completed(interval, limud_date)
+    }
+    fn date_for_unit(&self, cycle: &Cycle, index: i32) -> Option<HebrewDate> {
+        self.calculator.date_for_unit(cycle, index)
+    }
+}
+impl<T, C: LimudCalculator<T>> LimudCalculator<T> for DropBeinHazmanim<C> {}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use crate::{
+        date::from_hebrew_date,
+        sequence::BavliDafim,
+        units::{Daf, Tractate},
+        DafYomiBavli, PacePlanner, SkipRules,
+    };
+
+    use super::*;
+
+    #[test]
+    fn zman_calendar_standard_zmanim() {
+        let zmanim = ZmanCalendar::new();
+        let elul = zmanim
+            .zman(&from_hebrew_date(5784, HebrewMonth::Elul, 15))
+            .expect("date is in zman");
+        assert_eq!(elul.zman, Zman::Elul);
+        assert_eq!(elul.start_date, from_hebrew_date(5784, HebrewMonth::Elul, 1));
+        assert_eq!(elul.end_date, from_hebrew_date(5785, HebrewMonth::Tishrei, 9));
+        let winter = zmanim
+            .zman(&from_hebrew_date(5785, HebrewMonth::Shevat, 1))
+            .expect("date is in zman");
+        assert_eq!(winter.zman, Zman::Winter);
+        assert_eq!(winter.end_date, from_hebrew_date(5785, HebrewMonth::Adar, 29));
+        assert!(zmanim.is_bein_hazmanim(&from_hebrew_date(5785, HebrewMonth::Nissan, 15)));
+        assert!(zmanim.is_bein_hazmanim(&from_hebrew_date(5785, HebrewMonth::Av, 9)));
+    }
+
+    #[test]
+    fn zman_calendar_next_zman() {
+        let zmanim = ZmanCalendar::new();
+        let next = zmanim
+            .next_zman(&from_hebrew_date(5785, HebrewMonth::Tishrei, 15))
+            .expect("zman exists");
+        assert_eq!(next.zman, Zman::Winter);
+        assert_eq!(next.start_date, from_hebrew_date(5785, HebrewMonth::Cheshvan, 1));
+        let next = zmanim
+            .next_zman(&from_hebrew_date(5785, HebrewMonth::Av, 20))
+            .expect("zman exists");
+        assert_eq!(next.zman, Zman::Elul);
+    }
+
+    #[test]
+    fn zman_calendar_custom_edges() {
+        // Winter zman from Isru Chag Sukkos until Rosh Chodesh Nissan
+        let zmanim = ZmanCalendar::new().edges(
+            Zman::Winter,
+            ZmanEdge::new(HebrewMonth::Tishrei, 23),
+            ZmanEdge::new(HebrewMonth::Nissan, 2),
+        );
+        let isru_chag = from_hebrew_date(5785, HebrewMonth::Tishrei, 23);
+        assert_eq!(zmanim.zman(&isru_chag).map(|period| period.zman), Some(Zman::Winter));
+        assert!(!zmanim.is_bein_hazmanim(&from_hebrew_date(5785, HebrewMonth::Nissan, 1)));
+    }
+
+    #[test]
+    fn drop_bein_hazmanim_calculator() {
+        let calculator = DropBeinHazmanim::new(DafYomiBavli::default(), ZmanCalendar::new());
+        assert!(calculator
+            .limud(from_hebrew_date(5785, HebrewMonth::Tishrei, 15))
+            .is_none());
+        // The dafim of Sukkos are dropped, so zman resumes with the public schedule
+        let cheshvan = from_hebrew_date(5785, HebrewMonth::Cheshvan, 1);
+        assert_eq!(calculator.limud(cheshvan), DafYomiBavli::default().limud(cheshvan));
+        assert_eq!(
+            calculator.progress(cheshvan),
+            DafYomiBavli::default().progress(cheshvan)
+        );
+    }
+
+    #[test]
+    fn during_zman_pace_planner() {
+        let start = from_hebrew_date(5785, HebrewMonth::Adar, 20);
+        let planner = PacePlanner::new(
+            BavliDafim,
+            Daf {
+                tractate: Tractate::Berachos,
+                page: 2,
+            },
+            Daf {
+                tractate: Tractate::Berachos,
+                page: 21,
+            },
+            start,
+            from_hebrew_date(5785, HebrewMonth::Iyar, 10),
+        )
+        .expect("valid plan")
+        .skip_rules(SkipRules::new().skip_bein_hazmanim(ZmanCalendar::new()));
+        // 10 days left in Adar and 10 days of Iyar
+        assert_eq!(planner.learning_days(), 20);
+        assert!(planner.limud(from_hebrew_date(5785, HebrewMonth::Nissan, 10)).is_none());
+    }
+}